@@ -0,0 +1,262 @@
+use crate::errors::*;
+use crate::fetch::*;
+use crate::parse::*;
+use crate::types::*;
+use reqwest::blocking::{Client, ClientBuilder, Response};
+use reqwest::header::HeaderMap;
+use reqwest::Proxy;
+use std::io::{self, Write};
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+impl YoutubeTranscript {
+    /// Fetches the transcript for a given YouTube video ID or URL, blocking the current thread.
+    ///
+    /// This mirrors `fetch_transcript` using `reqwest::blocking::Client`, so it can be used
+    /// without an async runtime. It must not be called from within an async context.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - A vector of `TranscriptResponse` if the transcript is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub fn fetch_transcript_blocking(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = build_client(config)?;
+
+        // Step 1: Prepare headers for the requests
+        let headers = Self::build_headers(config)?;
+
+        // Step 2: Fetch the video page and extract its caption tracks
+        let id = Self::parse_video_id(video_id)?;
+        let video_page_body = fetch_video_page(&client, &id, &headers, config)?;
+        let caption_tracks = Self::parse_caption_tracks(&video_page_body, config, video_id)?;
+
+        // Step 3: Select the caption track and retrieve its URL
+        let track = Self::resolve_transcript_track(&caption_tracks, config, video_id)?;
+
+        // Step 4: Fetch the transcript content
+        let transcript_response =
+            send_request(&client, &track.url, None, &headers, config, video_id)?;
+        Self::check_transcript_status(transcript_response.status(), video_id)?;
+
+        let transcript_body = read_body(transcript_response, config, video_id)?;
+
+        // Step 5: Parse the XML transcript
+        let transcript = TranscriptBody::from_track(track, transcript_body, config);
+        Self::parse_transcript(transcript, video_id)
+    }
+}
+
+/// Builds the blocking HTTP client, applying the client options from the config.
+///
+/// # Arguments
+///
+/// * `config` - An optional `TranscriptConfig` providing client options such as the proxy and
+///   connection pool settings.
+///
+/// # Returns
+///
+/// * `Ok(Client)` - The configured blocking HTTP client.
+/// * `Err(YoutubeTranscriptError)` - An error if the client cannot be built from the config.
+fn build_client(config: Option<&TranscriptConfig>) -> Result<Client, YoutubeTranscriptError> {
+    let builder = YoutubeTranscript::configure_client(Client::builder(), config)?;
+    Ok(builder.build()?)
+}
+
+impl ClientOptions for ClientBuilder {
+    fn decompress(self) -> Self {
+        self.gzip(true).brotli(true)
+    }
+
+    fn proxy(self, proxy: Proxy) -> Self {
+        self.proxy(proxy)
+    }
+
+    fn pool_max_idle_per_host(self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host(max_idle)
+    }
+
+    fn pool_idle_timeout(self, timeout: Duration) -> Self {
+        self.pool_idle_timeout(timeout)
+    }
+
+    fn tcp_keepalive(self, interval: Duration) -> Self {
+        self.tcp_keepalive(interval)
+    }
+}
+
+/// Fetches the HTML content of the video page, blocking the current thread.
+///
+/// With `Backend::InnerTube`, the player response is requested from the InnerTube API instead.
+///
+/// When `retry_on_captcha` is set in the config, a captcha page is fetched again with
+/// exponential backoff, up to `max_retries` times.
+///
+/// # Arguments
+///
+/// * `client` - The blocking HTTP client used for the request.
+/// * `video_id` - The ID of the YouTube video.
+/// * `headers` - The headers sent with the request.
+/// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The HTML content of the video page, or the InnerTube player response.
+/// * `Err(YoutubeTranscriptError)` - An error if the page cannot be fetched.
+fn fetch_video_page(
+    client: &Client,
+    video_id: &VideoId,
+    headers: &HeaderMap,
+    config: Option<&TranscriptConfig>,
+) -> Result<String, YoutubeTranscriptError> {
+    let (video_page_url, request_body) = YoutubeTranscript::video_page_request(video_id, config);
+    let video_id = video_id.as_str();
+
+    let mut attempt = 0;
+    loop {
+        let video_page_response = send_request(
+            client,
+            &video_page_url,
+            request_body.as_ref(),
+            headers,
+            config,
+            video_id,
+        )?;
+        let video_page_body = read_body(video_page_response, config, video_id)?;
+        let Some(delay) = YoutubeTranscript::captcha_retry_delay(&video_page_body, attempt, config)
+        else {
+            return Ok(video_page_body);
+        };
+
+        std::thread::sleep(delay);
+        attempt += 1;
+    }
+}
+
+/// Sends a blocking GET request, or a POST request with a JSON body, applying the request
+/// options from the config.
+///
+/// Network errors and `5xx`/`429` responses are retried with exponential backoff when
+/// `max_retries` is set in the config.
+///
+/// # Arguments
+///
+/// * `client` - The blocking HTTP client used for the request.
+/// * `url` - The URL to request.
+/// * `body` - The JSON body to post, or `None` to send a GET request.
+/// * `headers` - The headers sent with the request.
+/// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+/// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+///
+/// # Returns
+///
+/// * `Ok(Response)` - The response if the request was sent successfully.
+/// * `Err(YoutubeTranscriptError)` - An error if the request failed or all retries were exhausted.
+fn send_request(
+    client: &Client,
+    url: &str,
+    body: Option<&serde_json::Value>,
+    headers: &HeaderMap,
+    config: Option<&TranscriptConfig>,
+    video_id: &str,
+) -> Result<Response, YoutubeTranscriptError> {
+    let mut attempt = 0;
+    loop {
+        let mut request = match body {
+            Some(body) => client.post(url).json(body),
+            None => client.get(url),
+        };
+        request = request.headers(headers.clone());
+        if let Some(timeout) = config.and_then(|c| c.timeout) {
+            request = request.timeout(timeout);
+        }
+        let result = request.send();
+
+        match YoutubeTranscript::retry_request(result, Response::status, attempt, config, video_id)
+        {
+            ControlFlow::Break(result) => return result,
+            ControlFlow::Continue(delay) => std::thread::sleep(delay),
+        }
+        attempt += 1;
+    }
+}
+
+/// Reads the body of a blocking response as text, enforcing `max_body_bytes` from the config.
+///
+/// # Arguments
+///
+/// * `response` - The response whose body is read.
+/// * `config` - An optional `TranscriptConfig` providing the body size limit.
+/// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The response body.
+/// * `Err(YoutubeTranscriptError)` - An error if the body cannot be read or exceeds the limit.
+fn read_body(
+    mut response: Response,
+    config: Option<&TranscriptConfig>,
+    video_id: &str,
+) -> Result<String, YoutubeTranscriptError> {
+    let Some(limit) = config.and_then(|c| c.max_body_bytes) else {
+        return response
+            .text()
+            .map_err(|e| YoutubeTranscript::request_error(e, video_id));
+    };
+
+    let mut body = LimitedBody::new(limit, response.content_length(), video_id)?;
+    if let Err(error) = response.copy_to(&mut body) {
+        if body.exceeded {
+            return Err(YoutubeTranscript::response_too_large(video_id, limit));
+        }
+        return Err(YoutubeTranscript::request_error(error, video_id));
+    }
+    Ok(body.into_text())
+}
+
+/// Writing a blocking response into a `LimitedBody` fails once more than its limit is written.
+impl Write for LimitedBody {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.push(buf) {
+            return Err(io::Error::other("response body size limit exceeded"));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_transcript_blocking_with_invalid_url() {
+        let url = "https://www.example.com/watch?v=dQw4w9WgXcQ";
+        let result = YoutubeTranscript::fetch_transcript_blocking(url, None);
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidVideoId)
+        ));
+    }
+
+    #[test]
+    fn test_limited_body_write() {
+        let mut body = LimitedBody::new(4, None, "dQw4w9WgXcQ").unwrap();
+        assert!(body.write_all(b"abc").is_ok());
+        assert!(!body.exceeded);
+        assert!(body.write_all(b"de").is_err());
+        assert!(body.exceeded);
+        assert_eq!(body.into_text(), "abc");
+    }
+}
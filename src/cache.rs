@@ -0,0 +1,266 @@
+use crate::errors::*;
+use crate::fetch::*;
+use crate::types::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// An in-memory cache of fetched transcripts, keyed by video ID and the options of the request.
+///
+/// Cached transcripts expire after a fixed time to live. The cache can be shared between tasks,
+/// e.g. behind an `Arc`, so repeated requests for the same video are served without hitting YouTube.
+#[derive(Debug)]
+pub struct TranscriptCache {
+    /// How long a cached transcript is returned after it was stored.
+    ttl: Duration,
+    /// The cached transcripts, keyed by video ID and the key built by `key_for`.
+    entries: Mutex<HashMap<(String, String), CachedTranscript>>,
+}
+
+/// A transcript stored in a `TranscriptCache`.
+#[derive(Debug)]
+struct CachedTranscript {
+    /// When the transcript was stored.
+    stored_at: Instant,
+    /// The transcript entries.
+    transcript: Vec<TranscriptResponse>,
+}
+
+impl TranscriptCache {
+    /// Creates an empty cache whose transcripts expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Builds the key under which transcripts fetched with a config are cached.
+    ///
+    /// The key covers every option that changes the fetched entries: the requested languages,
+    /// the translation target, the track preferences, the parsing options, and where the
+    /// caption tracks are retrieved from, i.e. the backend and base URL. The translation
+    /// target has its own segment, so a translation into `en` never answers a request for an
+    /// original `en` track.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - An optional `TranscriptConfig`, as passed to `fetch_transcript`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The cache key for the config.
+    pub fn key_for(config: Option<&TranscriptConfig>) -> String {
+        let default = TranscriptConfig::default();
+        let config = config.unwrap_or(&default);
+        let flags = [
+            (config.prefer_manual, "manual"),
+            (config.generated_only, "generated"),
+            (config.language_fallback, "fallback"),
+            (config.word_level, "words"),
+            (config.strict, "strict"),
+            (config.preserve_newlines, "newlines"),
+            (config.keep_markup, "markup"),
+        ];
+        let mut key = format!(
+            "lang:{};tlang:{};hl:{};fmt:{};backend:{:?};base:{}",
+            config.requested_languages().join(","),
+            config.translate_to.as_deref().unwrap_or_default(),
+            config.page_language.as_deref().unwrap_or_default(),
+            config.format.as_deref().unwrap_or_default(),
+            config.backend,
+            config.base_url.as_deref().unwrap_or_default()
+        );
+        for (enabled, flag) in flags {
+            if enabled {
+                key.push(';');
+                key.push_str(flag);
+            }
+        }
+        key
+    }
+
+    /// Returns a copy of the cached transcript for a video and key, if it has not expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The YouTube video ID.
+    /// * `key` - The key the transcript was stored under, e.g. as built by `key_for`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Vec<TranscriptResponse>>` - The cached entries, or `None` if absent or expired.
+    pub fn get(&self, video_id: &str, key: &str) -> Option<Vec<TranscriptResponse>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (video_id.to_string(), key.to_string());
+        match entries.get(&key) {
+            Some(cached) if cached.stored_at.elapsed() < self.ttl => {
+                Some(cached.transcript.clone())
+            }
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores the transcript for a video and key, replacing any previous entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The YouTube video ID.
+    /// * `key` - The key to store the transcript under, e.g. as built by `key_for`.
+    /// * `transcript` - The transcript entries to cache.
+    pub fn put(&self, video_id: &str, key: &str, transcript: Vec<TranscriptResponse>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            (video_id.to_string(), key.to_string()),
+            CachedTranscript {
+                stored_at: Instant::now(),
+                transcript,
+            },
+        );
+    }
+
+    /// Removes every cached transcript.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Fetches the transcript for a given YouTube video ID or URL, consulting the cache first.
+    ///
+    /// Transcripts are cached under the video ID and the key built by `key_for` from the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - The cached or freshly fetched transcript entries.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript is not cached and cannot be fetched.
+    pub async fn fetch_transcript(
+        &self,
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        // Step 1: Build the cache key for the request
+        let identifier = YoutubeTranscript::retrieve_video_id(video_id)?;
+        let key = Self::key_for(config.as_ref());
+
+        // Step 2: Return the cached transcript if it is still fresh
+        if let Some(transcript) = self.get(&identifier, &key) {
+            return Ok(transcript);
+        }
+
+        // Step 3: Fetch the transcript and cache it
+        let transcript = YoutubeTranscript::fetch_transcript(&identifier, config).await?;
+        self.put(&identifier, &key, transcript.clone());
+        Ok(transcript)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, offset: f64, duration: f64) -> TranscriptResponse {
+        TranscriptResponse {
+            text: text.to_string(),
+            duration,
+            offset,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        }
+    }
+
+    #[test]
+    fn test_cache_get_and_put() {
+        let cache = TranscriptCache::new(Duration::from_secs(60));
+        assert!(cache.get("dQw4w9WgXcQ", "en").is_none());
+
+        cache.put("dQw4w9WgXcQ", "en", vec![entry("hello", 0.0, 1.0)]);
+        let cached = cache.get("dQw4w9WgXcQ", "en").unwrap();
+        assert_eq!(cached[0].text, "hello");
+        assert!(cache.get("dQw4w9WgXcQ", "de").is_none());
+
+        cache.clear();
+        assert!(cache.get("dQw4w9WgXcQ", "en").is_none());
+    }
+
+    #[test]
+    fn test_cache_expires_entries() {
+        let cache = TranscriptCache::new(Duration::ZERO);
+        cache.put("dQw4w9WgXcQ", "en", vec![entry("hello", 0.0, 1.0)]);
+        assert!(cache.get("dQw4w9WgXcQ", "en").is_none());
+    }
+
+    #[test]
+    fn test_cache_key_for() {
+        let original = TranscriptConfig::builder().lang("en").build();
+        let translated = TranscriptConfig::builder().translate_to("en").build();
+        assert_ne!(
+            TranscriptCache::key_for(Some(&original)),
+            TranscriptCache::key_for(Some(&translated))
+        );
+
+        let manual = TranscriptConfig::builder()
+            .lang("en")
+            .prefer_manual(true)
+            .build();
+        let words = TranscriptConfig::builder()
+            .lang("en")
+            .word_level(true)
+            .build();
+        assert_ne!(
+            TranscriptCache::key_for(Some(&original)),
+            TranscriptCache::key_for(Some(&manual))
+        );
+        assert_ne!(
+            TranscriptCache::key_for(Some(&manual)),
+            TranscriptCache::key_for(Some(&words))
+        );
+
+        let innertube = TranscriptConfig::builder()
+            .lang("en")
+            .backend(Backend::InnerTube)
+            .build();
+        let mock = TranscriptConfig::builder()
+            .lang("en")
+            .base_url("http://127.0.0.1:8080")
+            .build();
+        assert_ne!(
+            TranscriptCache::key_for(Some(&original)),
+            TranscriptCache::key_for(Some(&innertube))
+        );
+        assert_ne!(
+            TranscriptCache::key_for(Some(&original)),
+            TranscriptCache::key_for(Some(&mock))
+        );
+
+        let default = TranscriptConfig::default();
+        assert_eq!(
+            TranscriptCache::key_for(None),
+            TranscriptCache::key_for(Some(&default))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_fetch_transcript_returns_cached_entries() {
+        let cache = TranscriptCache::new(Duration::from_secs(60));
+        let config = TranscriptConfig::builder().lang("en").build();
+        let key = TranscriptCache::key_for(Some(&config));
+        cache.put("dQw4w9WgXcQ", &key, vec![entry("hello", 0.0, 1.0)]);
+
+        let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+        let transcript = cache.fetch_transcript(url, Some(config)).await.unwrap();
+        assert_eq!(transcript[0].text, "hello");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::types::TranscriptInfo;
 use thiserror::Error;
 
 /// An enumeration of possible errors that can occur when fetching YouTube transcripts.
@@ -7,25 +8,137 @@ pub enum YoutubeTranscriptError {
     #[error("YouTube is receiving too many requests from this IP and now requires solving a captcha to continue")]
     TooManyRequests,
 
-    /// Error indicating that the video is no longer available.
-    #[error("The video is no longer available ({0})")]
-    VideoUnavailable(String),
+    /// Error indicating that YouTube served its cookie consent page instead of the video page.
+    #[error("YouTube requires accepting cookies before showing this video ({0}); set a valid consent cookie")]
+    ConsentRequired(String),
+
+    /// Error indicating that the video is unavailable, e.g. because it was removed or is private.
+    #[error("The video is unavailable ({video_id}): {reason}")]
+    VideoUnavailable {
+        /// The YouTube video URL or ID.
+        video_id: String,
+        /// The reason given by YouTube, e.g. "This video is private".
+        reason: String,
+    },
+
+    /// Error indicating that the video is age-restricted and cannot be viewed without signing in.
+    #[error("The video is age-restricted and requires signing in to confirm your age ({0})")]
+    AgeRestricted(String),
+
+    /// Error indicating that the video is only available to members of the channel.
+    #[error("The video is only available to channel members ({0})")]
+    MembersOnly(String),
 
     /// Error indicating that transcripts are disabled for the video.
     #[error("Transcript is disabled on this video ({0})")]
     TranscriptDisabled(String),
 
     /// Error indicating that no transcripts are available for the video.
+    ///
+    /// This includes videos whose captions section is present but lists no caption tracks.
     #[error("No transcripts are available for this video ({0})")]
     TranscriptNotAvailable(String),
 
     /// Error indicating that no transcripts are available in the requested language.
+    ///
+    /// Holds the requested languages, the caption tracks that are available instead, and the video ID.
+    #[error(
+        "No transcripts are available in {0} for this video ({2}). Available languages: {available:?}",
+        available = language_codes(.1)
+    )]
+    TranscriptNotAvailableLanguage(String, Vec<TranscriptInfo>, String),
+
+    /// Error indicating that the transcript was fetched but no entries could be parsed from it,
+    /// or that the video page was truncated or malformed.
+    #[error("The transcript could not be parsed; its format may have changed ({0})")]
+    TranscriptParseFailed(String),
+
+    /// Error indicating that the captions section of the video page is not valid JSON.
+    ///
+    /// The underlying JSON error is available through `source()`.
     #[error(
-        "No transcripts are available in {0} for this video ({2}). Available languages: {1:?}"
+        "The captions of the video page could not be parsed; their format may have changed ({0})"
     )]
-    TranscriptNotAvailableLanguage(String, Vec<String>, String),
+    CaptionsParseFailed(String, #[source] serde_json::Error),
+
+    /// Error indicating that a request failed due to a network or HTTP client error.
+    #[cfg(feature = "fetch")]
+    #[error("Network error while fetching the transcript: {0}")]
+    NetworkError(#[from] reqwest::Error),
+
+    /// Error indicating that a request timed out before completing.
+    ///
+    /// The underlying request error is available through `source()`.
+    #[cfg(feature = "fetch")]
+    #[error("The request timed out while fetching the transcript ({0})")]
+    Timeout(String, #[source] reqwest::Error),
+
+    /// Error indicating that a request kept failing after all configured retries.
+    #[cfg(feature = "fetch")]
+    #[error("Giving up on this video after {attempts} attempts ({video_id})")]
+    RetriesExhausted {
+        /// The YouTube video URL or ID.
+        video_id: String,
+        /// The total number of attempts made.
+        attempts: u32,
+        /// The HTTP status of the last response, if the last attempt received one.
+        last_status: Option<u16>,
+        /// The error of the last attempt, if it failed without a response.
+        #[source]
+        last_error: Option<reqwest::Error>,
+    },
+
+    /// Error indicating that a response body exceeded the configured `max_body_bytes`.
+    #[error("The response exceeded the limit of {limit} bytes ({video_id})")]
+    ResponseTooLarge {
+        /// The YouTube video URL or ID.
+        video_id: String,
+        /// The configured maximum body size in bytes.
+        limit: usize,
+    },
+
+    /// Error indicating that the requested language code cannot be sent in a request header.
+    #[error("Invalid language code: {0:?}")]
+    InvalidLanguageCode(String),
+
+    /// Error indicating that the consent cookie cannot be sent in a request header.
+    #[error("Invalid consent cookie: {0:?}")]
+    InvalidConsentCookie(String),
+
+    /// Error indicating that the configured User-Agent is not a valid header value.
+    #[error("Invalid User-Agent: {0:?}")]
+    InvalidUserAgent(String),
+
+    /// Error indicating that a configured extra header has an invalid name or value.
+    #[error("Invalid header: {0:?}")]
+    InvalidHeader(String),
+
+    /// Error indicating that the configured proxy URL cannot be used.
+    #[error("Invalid proxy URL: {0}")]
+    InvalidProxy(String),
 
     /// Error indicating that it was impossible to retrieve the YouTube video ID.
     #[error("Impossible to retrieve Youtube video ID.")]
     InvalidVideoId,
 }
+
+impl YoutubeTranscriptError {
+    /// Returns the caption tracks available for the video, as carried by `TranscriptNotAvailableLanguage`.
+    ///
+    /// Any of them can be fetched with `YoutubeTranscript::fetch_transcript_from_info` without
+    /// downloading the video page again. Other errors carry no tracks.
+    pub fn available_transcripts(&self) -> &[TranscriptInfo] {
+        match self {
+            Self::TranscriptNotAvailableLanguage(_, tracks, _) => tracks,
+            _ => &[],
+        }
+    }
+}
+
+/// Returns the language codes of the given caption tracks, for use in error messages.
+fn language_codes(tracks: &[TranscriptInfo]) -> Vec<&str> {
+    tracks
+        .iter()
+        .map(|track| track.language_code.as_str())
+        .collect()
+}
@@ -1,12 +1,112 @@
 use crate::errors::*;
+use crate::parse::*;
 use crate::regex::*;
 use crate::types::*;
-use regex::Regex;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::Client;
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Proxy, Response, StatusCode, Url};
+use std::collections::HashMap;
+use std::ops::{ControlFlow, Deref};
+use std::time::Duration;
 
 const USER_AGENT_STR: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_4) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/85.0.4183.83 Safari/537.36,gzip(gfe)";
 
+/// The value of the `CONSENT` cookie sent when `consent_cookie` is not set in the config.
+///
+/// It accepts YouTube's cookie consent interstitial, which is served in some regions (e.g. the EU)
+/// instead of the video page.
+const DEFAULT_CONSENT_COOKIE: &str = "YES+cb";
+
+/// The base delay between retries when `retry_delay` is not set in the config.
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// The base URL of YouTube used when `base_url` is not set in the config.
+const DEFAULT_BASE_URL: &str = "https://www.youtube.com";
+
+/// The transcript format requested when `format` is not set in the config.
+const DEFAULT_TRANSCRIPT_FORMAT: &str = "srv1";
+
+/// The number of videos fetched at the same time when `concurrency` is not set in the config.
+const DEFAULT_CONCURRENCY: usize = 5;
+
+/// The InnerTube client the player response is requested as, when using `Backend::InnerTube`.
+const INNERTUBE_CLIENT_NAME: &str = "WEB";
+
+/// The version of the InnerTube client sent with player requests.
+const INNERTUBE_CLIENT_VERSION: &str = "2.20240726.00.00";
+
+/// The transcript URL and details of the caption track selected for fetching.
+pub(crate) struct SelectedTrack {
+    /// The URL from which the transcript content is fetched.
+    pub(crate) url: String,
+    /// The language code reported on the entries.
+    pub(crate) lang: String,
+    /// The human-readable language name reported on the entries, if known.
+    pub(crate) language_name: Option<String>,
+    /// Whether the caption track was automatically generated by YouTube.
+    pub(crate) is_generated: bool,
+    /// The language code of the caption track, if the transcript is a machine translation of it.
+    pub(crate) translated_from: Option<String>,
+}
+
+/// The caption tracks listed on a video page, in the order YouTube lists them.
+#[derive(Debug)]
+pub(crate) struct CaptionTracks {
+    /// The raw caption tracks.
+    pub(crate) tracks: Vec<serde_json::Value>,
+    /// The index of the video's default track, as determined by `default_caption_track_index`.
+    pub(crate) default_index: Option<usize>,
+}
+
+impl From<Vec<serde_json::Value>> for CaptionTracks {
+    fn from(tracks: Vec<serde_json::Value>) -> Self {
+        Self {
+            tracks,
+            default_index: None,
+        }
+    }
+}
+
+impl Deref for CaptionTracks {
+    type Target = [serde_json::Value];
+
+    fn deref(&self) -> &Self::Target {
+        &self.tracks
+    }
+}
+
+impl TranscriptBody {
+    /// Creates the transcript body fetched for a selected caption track.
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - The caption track the body was fetched from.
+    /// * `body` - The transcript body as served by YouTube.
+    /// * `config` - An optional `TranscriptConfig` providing the parsing options.
+    ///
+    /// # Returns
+    ///
+    /// * `TranscriptBody` - The transcript body along with the details of the track.
+    pub(crate) fn from_track(
+        track: SelectedTrack,
+        body: String,
+        config: Option<&TranscriptConfig>,
+    ) -> Self {
+        Self {
+            body,
+            url: track.url,
+            lang: track.lang,
+            language_name: track.language_name,
+            is_generated: track.is_generated,
+            translated_from: track.translated_from,
+            word_level: config.is_some_and(|c| c.word_level),
+            strict: config.is_some_and(|c| c.strict),
+            preserve_newlines: config.is_some_and(|c| c.preserve_newlines),
+            keep_markup: config.is_some_and(|c| c.keep_markup),
+        }
+    }
+}
+
 /// A struct providing functionality to fetch YouTube transcripts.
 pub struct YoutubeTranscript;
 
@@ -26,186 +126,3286 @@ impl YoutubeTranscript {
         video_id: &str,
         config: Option<TranscriptConfig>,
     ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
-        // Step 1: Retrieve video identifier from URL or ID
-        let identifier = Self::retrieve_video_id(video_id)?;
+        let client = Self::build_client(config.as_ref())?;
+        Self::fetch_transcript_with_client(&client, video_id, config).await
+    }
 
-        // Step 2: Create HTTP client
-        let client = Client::new();
+    /// Fetches the transcript for a given YouTube video ID or URL as a `Transcript`.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Transcript)` - The transcript if it is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Transcript, YoutubeTranscriptError> {
+        Self::fetch_transcript(video_id, config)
+            .await
+            .map(Transcript::from)
+    }
 
-        // Step 3: Construct video page URL
-        let video_page_url = format!("https://www.youtube.com/watch?v={}", identifier);
+    /// Fetches the transcript for a given YouTube video ID or URL as a single string.
+    ///
+    /// The decoded text of all entries is joined with single spaces, as by `to_plain_text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The full transcript text.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_text(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<String, YoutubeTranscriptError> {
+        let transcript = Self::fetch(video_id, config).await?;
+        Ok(transcript.to_plain_text())
+    }
 
-        // Step 4: Prepare headers for the request
-        let mut headers = HeaderMap::new();
-        headers.insert("User-Agent", HeaderValue::from_static(USER_AGENT_STR));
-        if let Some(config) = &config {
-            if let Some(lang) = &config.lang {
-                headers.insert("Accept-Language", HeaderValue::from_str(&lang).unwrap());
-            }
-        }
+    /// Fetches the transcript for an already validated `VideoId`.
+    ///
+    /// The ID is used as is, without recognizing URLs in it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - A vector of `TranscriptResponse` if the transcript is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_by_id(
+        video_id: &VideoId,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let client = Self::build_client(config.as_ref())?;
+        let transcript = Self::fetch_transcript_body(&client, video_id, config.as_ref()).await?;
+        Self::parse_transcript(transcript, video_id.as_str())
+    }
 
-        // Step 5: Fetch the video page content
-        let video_page_response = client
-            .get(&video_page_url)
-            .headers(headers.clone())
-            .send()
-            .await
-            .map_err(|_| YoutubeTranscriptError::TranscriptDisabled(video_id.to_string()))?;
+    /// Fetches the transcript for a given YouTube video ID or URL, along with the track it came from.
+    ///
+    /// This is useful for debugging and caching, since it reveals which of several caption
+    /// tracks was selected and the exact URL its transcript was fetched from.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DetailedTranscript)` - The transcript entries and the selected track.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_detailed(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<DetailedTranscript, YoutubeTranscriptError> {
+        let client = Self::build_client(config.as_ref())?;
+        let id = Self::parse_video_id(video_id)?;
+        let transcript = Self::fetch_transcript_body(&client, &id, config.as_ref()).await?;
+        let track = TranscriptInfo {
+            language_code: transcript.lang.clone(),
+            language_name: transcript.language_name.clone(),
+            is_generated: transcript.is_generated,
+            base_url: transcript.url.clone(),
+        };
+        let is_translated = transcript.translated_from.is_some();
+        let source_lang = transcript
+            .translated_from
+            .clone()
+            .unwrap_or_else(|| transcript.lang.clone());
+        let entries = Self::parse_transcript(transcript, video_id)?;
+        Ok(DetailedTranscript {
+            track,
+            entries,
+            is_translated,
+            source_lang,
+        })
+    }
 
-        let video_page_body = video_page_response
-            .text()
-            .await
-            .map_err(|_| YoutubeTranscriptError::TranscriptDisabled(video_id.to_string()))?;
+    /// Fetches the transcript for a given YouTube video ID or URL using a caller-provided HTTP client.
+    ///
+    /// Reusing a client enables connection pooling and lets callers configure proxies, TLS options,
+    /// and timeouts once for many requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The `reqwest::Client` used for all requests.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - A vector of `TranscriptResponse` if the transcript is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_with_client(
+        client: &Client,
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        // Step 1: Fetch the raw transcript content
+        let id = Self::parse_video_id(video_id)?;
+        let transcript = Self::fetch_transcript_body(client, &id, config.as_ref()).await?;
 
-        // Step 6: Split the HTML content to find the captions section
-        let splitted_html: Vec<&str> = video_page_body.split("\"captions\":").collect();
+        // Step 2: Parse the XML transcript
+        Self::parse_transcript(transcript, video_id)
+    }
 
-        // Step 7: Handle cases where captions are not found
-        if splitted_html.len() <= 1 {
-            if video_page_body.contains("class=\"g-recaptcha\"") {
-                return Err(YoutubeTranscriptError::TooManyRequests);
-            }
-            if !video_page_body.contains("\"playabilityStatus\":") {
-                return Err(YoutubeTranscriptError::VideoUnavailable(video_id.to_string()));
-            }
-            return Err(YoutubeTranscriptError::TranscriptDisabled(video_id.to_string()));
-        }
+    /// Fetches the transcript for a given YouTube video ID or URL and parses its entries lazily.
+    ///
+    /// The transcript body is downloaded once, but each entry is only parsed when the iterator
+    /// reaches it, so callers that stop early avoid parsing and allocating the remainder.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TranscriptIter)` - An iterator over the `TranscriptResponse` entries of the transcript.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_iter(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<TranscriptIter, YoutubeTranscriptError> {
+        let client = Self::build_client(config.as_ref())?;
+        let id = Self::parse_video_id(video_id)?;
+        let transcript = Self::fetch_transcript_body(&client, &id, config.as_ref()).await?;
+        Ok(TranscriptIter::new(transcript))
+    }
 
-        // Step 8: Parse the captions JSON
-        let captions: Option<serde_json::Value> = serde_json::from_str(
-            &splitted_html[1].split(",\"videoDetails").collect::<Vec<&str>>()[0].replace("\n", ""),
-        )
-        .ok();
+    /// Fetches the transcript for a given YouTube video ID or URL as a stream of entries.
+    ///
+    /// The transcript body is downloaded once, then its entries are yielded one at a time, so
+    /// they can be composed with `StreamExt` combinators such as `filter` or `chunks`.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Stream<Item = TranscriptResponse>)` - A stream of the transcript entries.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_stream(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<impl Stream<Item = TranscriptResponse>, YoutubeTranscriptError> {
+        let entries = Self::fetch_transcript_iter(video_id, config).await?;
+        Ok(stream::iter(entries))
+    }
 
-        // Step 9: Extract player captions renderer
-        let player_captions_renderer = captions
-            .as_ref()
-            .and_then(|c| c.get("playerCaptionsTracklistRenderer"));
+    /// Fetches the first entries of the transcript for a given YouTube video ID or URL.
+    ///
+    /// The transcript body is downloaded once, but parsing stops after `n` entries, which makes
+    /// this suitable for previews of long transcripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    /// * `n` - The maximum number of entries to return.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - At most `n` entries from the start of the transcript.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched, or if a
+    ///   non-empty body yields no entries.
+    pub async fn fetch_transcript_head(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+        n: usize,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let client = Self::build_client(config.as_ref())?;
+        let id = Self::parse_video_id(video_id)?;
+        let transcript = Self::fetch_transcript_body(&client, &id, config.as_ref()).await?;
+        let is_empty = transcript.body.trim().is_empty();
 
-        if player_captions_renderer.is_none() {
-            return Err(YoutubeTranscriptError::TranscriptDisabled(video_id.to_string()));
+        let entries: Vec<_> = TranscriptIter::new(transcript).take(n).collect();
+        if n > 0 && entries.is_empty() && !is_empty {
+            return Err(YoutubeTranscriptError::TranscriptParseFailed(
+                video_id.to_string(),
+            ));
         }
+        Ok(entries)
+    }
 
-        // Step 10: Extract caption tracks
-        let caption_tracks = player_captions_renderer
-            .unwrap()
-            .get("captionTracks")
-            .ok_or(YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()))?;
-
-        let caption_tracks =
-            caption_tracks.as_array().ok_or(YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()))?;
-
-        // Step 11: Check for specific language availability if provided in config
-        if let Some(lang) = config.as_ref().and_then(|c| c.lang.clone()) {
-            let lang_clone = lang.clone();
-            if !caption_tracks.iter().any(|track| {
-                track.get("languageCode").and_then(|v| v.as_str()) == Some(&lang_clone)
-            }) {
-                let available_langs = caption_tracks
-                    .iter()
-                    .filter_map(|track| {
-                        track.get("languageCode").and_then(|lc| lc.as_str().map(String::from))
-                    })
-                    .collect();
-                return Err(YoutubeTranscriptError::TranscriptNotAvailableLanguage(
-                    lang,
-                    available_langs,
-                    video_id.to_string(),
-                ));
-            }
-        }
+    /// Fetches the transcripts for several YouTube video IDs or URLs concurrently.
+    ///
+    /// All requests share a single HTTP client. At most `concurrency` videos (from the config,
+    /// defaulting to 5) are fetched at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_ids` - A slice of string slices representing YouTube video URLs or IDs.
+    /// * `config` - An optional `TranscriptConfig` applied to every video.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Result<Vec<TranscriptResponse>, YoutubeTranscriptError>)>)` - The result
+    ///   for each input, paired with that input and in the same order.
+    /// * `Err(YoutubeTranscriptError)` - An error if the HTTP client cannot be built from the config.
+    pub async fn fetch_transcripts(
+        video_ids: &[&str],
+        config: Option<TranscriptConfig>,
+    ) -> Result<
+        Vec<(
+            String,
+            Result<Vec<TranscriptResponse>, YoutubeTranscriptError>,
+        )>,
+        YoutubeTranscriptError,
+    > {
+        let client = Self::build_client(config.as_ref())?;
+        let concurrency = config
+            .as_ref()
+            .and_then(|c| c.concurrency)
+            .unwrap_or(DEFAULT_CONCURRENCY)
+            .max(1);
 
-        // Step 12: Retrieve the transcript URL
-        let transcript_url = caption_tracks
-            .iter()
-            .find(|track| {
-                config.as_ref().map_or(true, |c| {
-                    track.get("languageCode") == Some(&c.lang.clone().unwrap().into())
-                })
+        let results = stream::iter(video_ids)
+            .map(|&video_id| {
+                let client = &client;
+                let config = config.clone();
+                async move {
+                    let result = Self::fetch_transcript_with_client(client, video_id, config).await;
+                    (video_id.to_string(), result)
+                }
             })
-            .and_then(|track| track.get("baseUrl"))
-            .and_then(|url| url.as_str())
-            .ok_or(YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()))?;
+            .buffered(concurrency)
+            .collect()
+            .await;
+        Ok(results)
+    }
 
-        // Step 13: Fetch the transcript content
-        let transcript_response = client
-            .get(transcript_url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|_| YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()))?;
+    /// Fetches the transcript of a YouTube video in several languages.
+    ///
+    /// The video page is fetched once, then the caption track of each language is fetched
+    /// concurrently. At most `concurrency` tracks (from the config, defaulting to 5) are fetched
+    /// at the same time. The `lang` and `languages` of the config are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `langs` - The language codes to fetch.
+    /// * `config` - An optional `TranscriptConfig` applied to every language.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, Result<Vec<TranscriptResponse>, YoutubeTranscriptError>>)` - The result
+    ///   for each requested language, keyed by the language code as given.
+    /// * `Err(YoutubeTranscriptError)` - An error if the video page cannot be fetched.
+    pub async fn fetch_transcripts_multilang(
+        video_id: &str,
+        langs: &[&str],
+        config: Option<TranscriptConfig>,
+    ) -> Result<
+        HashMap<String, Result<Vec<TranscriptResponse>, YoutubeTranscriptError>>,
+        YoutubeTranscriptError,
+    > {
+        let config = config.unwrap_or_default();
+        let client = Self::build_client(Some(&config))?;
 
-        if !transcript_response.status().is_success() {
-            return Err(YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()));
-        }
+        // Step 1: Prepare headers for the requests
+        let headers = Self::build_headers(Some(&config))?;
 
-        let transcript_body = transcript_response
-            .text()
-            .await
-            .map_err(|_| YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()))?;
-
-        // Step 14: Parse the XML transcript
-        let re_xml_transcript = Regex::new(RE_XML_TRANSCRIPT).unwrap();
-        let results: Vec<TranscriptResponse> = re_xml_transcript
-            .captures_iter(&transcript_body)
-            .map(|cap| TranscriptResponse {
-                text: cap[3].to_string(),
-                duration: cap[2].parse().unwrap_or(0.0),
-                offset: cap[1].parse().unwrap_or(0.0),
-                lang: config
-                    .as_ref()
-                    .and_then(|c| c.lang.clone())
-                    .unwrap_or_else(|| {
-                        caption_tracks[0]["languageCode"]
-                            .as_str()
-                            .unwrap()
-                            .to_string()
-                    }),
-            })
-            .collect();
+        // Step 2: Fetch the video page and extract its caption tracks once
+        let id = Self::parse_video_id(video_id)?;
+        let caption_tracks =
+            Self::fetch_caption_tracks(&client, &id, &headers, Some(&config)).await?;
 
+        // Step 3: Fetch the track of each language concurrently
+        let concurrency = config.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+        let results = stream::iter(langs)
+            .map(|&lang| {
+                let config = TranscriptConfig {
+                    lang: Some(lang.to_string()),
+                    languages: Vec::new(),
+                    ..config.clone()
+                };
+                let (client, headers, caption_tracks) = (&client, &headers, &caption_tracks);
+                async move {
+                    let result = async {
+                        let track = Self::resolve_transcript_track(
+                            caption_tracks,
+                            Some(&config),
+                            video_id,
+                        )?;
+                        let transcript =
+                            Self::fetch_track(client, track, headers, Some(&config), video_id)
+                                .await?;
+                        Self::parse_transcript(transcript, video_id)
+                    };
+                    (lang.to_string(), result.await)
+                }
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
         Ok(results)
     }
 
-    /// Retrieves the video ID from a given YouTube URL or string.
+    /// Fetches the raw transcript body for a given YouTube video ID or URL.
+    ///
+    /// The response is returned exactly as served by YouTube, without applying `RE_XML_TRANSCRIPT`.
+    /// This is useful for debugging format changes or for running a custom parser.
     ///
     /// # Arguments
     ///
     /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` - The video ID if successfully retrieved.
-    /// * `Err(YoutubeTranscriptError)` - An error if the video ID cannot be retrieved.
-    fn retrieve_video_id(video_id: &str) -> Result<String, YoutubeTranscriptError> {
-        if video_id.len() == 11 {
-            return Ok(video_id.to_string());
-        }
-        let re_youtube = Regex::new(RE_YOUTUBE).unwrap();
-        if let Some(caps) = re_youtube.captures(video_id) {
-            if let Some(matched) = caps.get(1) {
-                return Ok(matched.as_str().to_string());
-            }
-        }
-        Err(YoutubeTranscriptError::InvalidVideoId)
+    /// * `Ok(String)` - The raw transcript body if it is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_raw(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<String, YoutubeTranscriptError> {
+        let client = Self::build_client(config.as_ref())?;
+        let id = Self::parse_video_id(video_id)?;
+        let transcript = Self::fetch_transcript_body(&client, &id, config.as_ref()).await?;
+        Ok(transcript.body)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Fetches the transcript of a caption track directly from its URL.
+    ///
+    /// The video page is not fetched, so this avoids a round-trip when the track URL is already
+    /// known, e.g. from `list_transcripts`. The language and kind of each entry are read from
+    /// the URL's `lang` and `kind` parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The transcript URL of a caption track, e.g. `TranscriptInfo::base_url`.
+    /// * `config` - An optional `TranscriptConfig` providing request options and the parsing options.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - A vector of `TranscriptResponse` if the transcript is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_from_url(
+        base_url: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+        let headers = Self::build_headers(config)?;
+        let track = Self::track_from_url(base_url)?;
+        let transcript = Self::fetch_track(&client, track, &headers, config, base_url).await?;
+        Self::parse_transcript(transcript, base_url)
+    }
 
-    #[test]
-    fn test_retrieve_video_id_from_url() {
-        let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
-        let result = YoutubeTranscript::retrieve_video_id(url);
-        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    /// Fetches the transcript of a caption track described by a `TranscriptInfo`.
+    ///
+    /// Only the transcript is downloaded, so a track listed by `list_transcripts` or carried by a
+    /// `TranscriptNotAvailableLanguage` error can be fetched without fetching the video page again.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The caption track to fetch.
+    /// * `config` - An optional `TranscriptConfig` providing request options and the transcript format.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - A vector of `TranscriptResponse` if the transcript is successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    pub async fn fetch_transcript_from_info(
+        info: &TranscriptInfo,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+        let headers = Self::build_headers(config)?;
+
+        let format = config
+            .and_then(|c| c.format.as_deref())
+            .unwrap_or(DEFAULT_TRANSCRIPT_FORMAT);
+        let url = with_query_param(&info.base_url, "fmt", format).ok_or(
+            YoutubeTranscriptError::TranscriptNotAvailable(info.base_url.clone()),
+        )?;
+        let track = SelectedTrack {
+            url,
+            lang: info.language_code.clone(),
+            language_name: info.language_name.clone(),
+            is_generated: info.is_generated,
+            translated_from: None,
+        };
+
+        let transcript =
+            Self::fetch_track(&client, track, &headers, config, &info.base_url).await?;
+        Self::parse_transcript(transcript, &info.base_url)
     }
 
-    #[test]
-    fn test_retrieve_video_id_from_invalid_url() {
-        let url = "https://www.example.com/watch?v=dQw4w9WgXcQ";
-        let result = YoutubeTranscript::retrieve_video_id(url);
-        assert!(result.is_err());
+    /// Lists the transcripts available for a given YouTube video ID or URL.
+    ///
+    /// Only the video page is fetched; no transcript content is downloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptInfo>)` - A vector of `TranscriptInfo` describing each available caption track.
+    /// * `Err(YoutubeTranscriptError)` - An error if the caption tracks cannot be retrieved.
+    pub async fn list_transcripts(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<Vec<TranscriptInfo>, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+        let headers = Self::build_headers(config)?;
+        let id = Self::parse_video_id(video_id)?;
+        let caption_tracks = Self::fetch_caption_tracks(&client, &id, &headers, config).await?;
+
+        Ok(caption_tracks
+            .iter()
+            .filter_map(Self::parse_track_info)
+            .collect())
+    }
+
+    /// Checks whether a given YouTube video ID or URL has any transcript.
+    ///
+    /// Only the video page is fetched; no transcript content is downloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the video has at least one caption track.
+    /// * `Err(YoutubeTranscriptError)` - An error if the video page cannot be retrieved.
+    pub async fn has_transcript(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<bool, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+        let headers = Self::build_headers(config)?;
+        let id = Self::parse_video_id(video_id)?;
+        let caption_tracks = Self::fetch_caption_tracks(&client, &id, &headers, config).await;
+        Self::has_caption_tracks(caption_tracks)
+    }
+
+    /// Fetches the transcript body for a given YouTube video ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client used for the requests.
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TranscriptBody)` - The raw transcript body along with details of the selected track.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    async fn fetch_transcript_body(
+        client: &Client,
+        video_id: &VideoId,
+        config: Option<&TranscriptConfig>,
+    ) -> Result<TranscriptBody, YoutubeTranscriptError> {
+        // Step 1: Prepare headers for the requests
+        let headers = Self::build_headers(config)?;
+
+        // Step 2: Fetch the video page and extract its caption tracks
+        let caption_tracks = Self::fetch_caption_tracks(client, video_id, &headers, config).await?;
+
+        // Step 3: Select the caption track and retrieve its URL
+        let video_id = video_id.as_str();
+        let track = Self::resolve_transcript_track(&caption_tracks, config, video_id)?;
+
+        // Step 4: Fetch the transcript content
+        Self::fetch_track(client, track, &headers, config, video_id).await
+    }
+
+    /// Fetches the transcript content of a selected caption track.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client used for the request.
+    /// * `track` - The caption track to fetch.
+    /// * `headers` - The headers sent with the request.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TranscriptBody)` - The raw transcript body along with details of the track.
+    /// * `Err(YoutubeTranscriptError)` - An error if the transcript cannot be fetched.
+    async fn fetch_track(
+        client: &Client,
+        track: SelectedTrack,
+        headers: &HeaderMap,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> Result<TranscriptBody, YoutubeTranscriptError> {
+        let transcript_response =
+            Self::send_request(client, &track.url, None, headers, config, video_id).await?;
+        Self::check_transcript_status(transcript_response.status(), video_id)?;
+
+        let transcript_body = Self::read_body(transcript_response, config, video_id).await?;
+        Ok(TranscriptBody::from_track(track, transcript_body, config))
+    }
+
+    /// Checks that a transcript request succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status of the transcript response.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the status is a success.
+    /// * `Err(YoutubeTranscriptError)` - `TranscriptNotAvailable` otherwise.
+    pub(crate) fn check_transcript_status(
+        status: StatusCode,
+        video_id: &str,
+    ) -> Result<(), YoutubeTranscriptError> {
+        if !status.is_success() {
+            return Err(YoutubeTranscriptError::TranscriptNotAvailable(
+                video_id.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Describes the caption track behind a transcript URL, using the URL's query parameters.
+    ///
+    /// The `srv1` format is requested unless the URL already names a format.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The transcript URL of a caption track, e.g. `TranscriptInfo::base_url`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SelectedTrack)` - The track, with its language and kind taken from the URL.
+    /// * `Err(YoutubeTranscriptError)` - An error if `base_url` is not a valid URL.
+    fn track_from_url(base_url: &str) -> Result<SelectedTrack, YoutubeTranscriptError> {
+        let url = Url::parse(base_url)
+            .map_err(|_| YoutubeTranscriptError::TranscriptNotAvailable(base_url.to_string()))?;
+        let param = |key: &str| {
+            url.query_pairs()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        let mut transcript_url = base_url.to_string();
+        if param("fmt").is_none() {
+            transcript_url = with_query_param(base_url, "fmt", DEFAULT_TRANSCRIPT_FORMAT).ok_or(
+                YoutubeTranscriptError::TranscriptNotAvailable(base_url.to_string()),
+            )?;
+        }
+
+        let translated_from = param("tlang").and(param("lang"));
+        Ok(SelectedTrack {
+            url: transcript_url,
+            lang: param("tlang").or_else(|| param("lang")).unwrap_or_default(),
+            language_name: None,
+            is_generated: param("kind").as_deref() == Some("asr"),
+            translated_from,
+        })
+    }
+
+    /// Selects the caption track to fetch and resolves the URL of its transcript.
+    ///
+    /// # Arguments
+    ///
+    /// * `caption_tracks` - The caption tracks listed on the video page.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SelectedTrack)` - The transcript URL along with details of the selected track.
+    /// * `Err(YoutubeTranscriptError)` - An error if no track matches the config.
+    pub(crate) fn resolve_transcript_track(
+        caption_tracks: &CaptionTracks,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> Result<SelectedTrack, YoutubeTranscriptError> {
+        // Step 1: Select the caption track for the requested languages
+        let requested_langs = config.map(|c| c.requested_languages()).unwrap_or_default();
+        let track = Self::select_caption_track(caption_tracks, config);
+        if track.is_none() && !requested_langs.is_empty() {
+            let available_langs = caption_tracks
+                .iter()
+                .filter_map(Self::parse_track_info)
+                .collect();
+            return Err(YoutubeTranscriptError::TranscriptNotAvailableLanguage(
+                requested_langs.join(", "),
+                available_langs,
+                video_id.to_string(),
+            ));
+        }
+
+        // Step 2: Retrieve the transcript URL
+        let transcript_url = track
+            .and_then(|track| track.get("baseUrl"))
+            .and_then(|url| url.as_str())
+            .ok_or(YoutubeTranscriptError::TranscriptNotAvailable(
+                video_id.to_string(),
+            ))?;
+        // The legacy XML format is requested by default, since YouTube may otherwise serve JSON3.
+        let format = config
+            .and_then(|c| c.format.as_deref())
+            .unwrap_or(DEFAULT_TRANSCRIPT_FORMAT);
+        let mut transcript_url = with_query_param(transcript_url, "fmt", format).ok_or(
+            YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()),
+        )?;
+        if let Some(target_lang) = config.and_then(|c| c.translate_to.as_deref()) {
+            transcript_url = with_query_param(&transcript_url, "tlang", target_lang).ok_or(
+                YoutubeTranscriptError::TranscriptNotAvailable(video_id.to_string()),
+            )?;
+        }
+
+        // Step 3: Resolve the language reported on the entries
+        let source_lang = Self::resolve_language(track, config);
+        let (lang, language_name, translated_from) =
+            match config.and_then(|c| c.translate_to.clone()) {
+                // The track name describes the source language, not the translation.
+                Some(target_lang) => (target_lang, None, Some(source_lang)),
+                None => (source_lang, track.and_then(Self::track_name), None),
+            };
+
+        Ok(SelectedTrack {
+            url: transcript_url,
+            lang,
+            language_name,
+            is_generated: track.is_some_and(Self::is_generated_track),
+            translated_from,
+        })
+    }
+
+    /// Fetches the details of a given YouTube video ID or URL, such as its title and author.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VideoDetails)` - The details of the video if they are successfully fetched.
+    /// * `Err(YoutubeTranscriptError)` - An error if the details cannot be fetched.
+    pub async fn fetch_video_details(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<VideoDetails, YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+        let headers = Self::build_headers(config)?;
+        let id = Self::parse_video_id(video_id)?;
+        let video_page_body = Self::fetch_video_page(&client, &id, &headers, config).await?;
+        Self::parse_video_details(&video_page_body, video_id)
+    }
+
+    /// Fetches the transcript and the details of a given YouTube video ID or URL together.
+    ///
+    /// The video page is fetched only once, since it contains both the caption tracks and
+    /// the video details.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((VideoDetails, Vec<TranscriptResponse>))` - The details of the video and its transcript entries.
+    /// * `Err(YoutubeTranscriptError)` - An error if the details or the transcript cannot be fetched.
+    pub async fn fetch_transcript_with_details(
+        video_id: &str,
+        config: Option<TranscriptConfig>,
+    ) -> Result<(VideoDetails, Vec<TranscriptResponse>), YoutubeTranscriptError> {
+        let config = config.as_ref();
+        let client = Self::build_client(config)?;
+
+        // Step 1: Prepare headers for the requests
+        let headers = Self::build_headers(config)?;
+
+        // Step 2: Fetch the video page and extract both the caption tracks and the details
+        let id = Self::parse_video_id(video_id)?;
+        let video_page_body = Self::fetch_video_page(&client, &id, &headers, config).await?;
+        let caption_tracks = Self::parse_caption_tracks(&video_page_body, config, video_id)?;
+        let details = Self::parse_video_details(&video_page_body, video_id)?;
+
+        // Step 3: Select the caption track and fetch its transcript
+        let track = Self::resolve_transcript_track(&caption_tracks, config, video_id)?;
+        let transcript = Self::fetch_track(&client, track, &headers, config, video_id).await?;
+
+        // Step 4: Parse the transcript
+        let entries = Self::parse_transcript(transcript, video_id)?;
+        Ok((details, entries))
+    }
+
+    /// Builds the HTTP client used when the caller does not provide one.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - An optional `TranscriptConfig` providing client options such as the proxy and
+    ///   connection pool settings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Client)` - The configured HTTP client.
+    /// * `Err(YoutubeTranscriptError)` - An error if the client cannot be built from the config.
+    fn build_client(config: Option<&TranscriptConfig>) -> Result<Client, YoutubeTranscriptError> {
+        let builder = Self::configure_client(Client::builder(), config)?;
+        Ok(builder.build()?)
+    }
+
+    /// Applies the client options from the config to an HTTP client builder.
+    ///
+    /// This is shared by the async and the blocking client, whose builders only differ in type.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The client builder to configure.
+    /// * `config` - An optional `TranscriptConfig` providing client options such as the proxy and
+    ///   connection pool settings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(B)` - The configured client builder.
+    /// * `Err(YoutubeTranscriptError)` - An error if the proxy URL cannot be used.
+    pub(crate) fn configure_client<B: ClientOptions>(
+        builder: B,
+        config: Option<&TranscriptConfig>,
+    ) -> Result<B, YoutubeTranscriptError> {
+        // Compressed responses are decoded transparently, so bodies are never read as raw gzip.
+        let mut builder = builder.decompress();
+        if let Some(proxy_url) = config.and_then(|c| c.proxy.as_deref()) {
+            let proxy = Proxy::all(proxy_url)
+                .map_err(|_| YoutubeTranscriptError::InvalidProxy(proxy_url.to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(config) = config {
+            if let Some(max_idle) = config.pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max_idle);
+            }
+            if let Some(timeout) = config.pool_idle_timeout {
+                builder = builder.pool_idle_timeout(timeout);
+            }
+            if let Some(interval) = config.tcp_keepalive {
+                builder = builder.tcp_keepalive(interval);
+            }
+        }
+        Ok(builder)
+    }
+
+    /// Builds the headers sent with every request.
+    ///
+    /// `Accept-Language` is sent for `page_language` if set, and otherwise for the first
+    /// requested language that is not blank.
+    /// Extra headers from the config are applied last, replacing any default of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - An optional `TranscriptConfig` specifying the desired language for the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HeaderMap)` - The request headers.
+    /// * `Err(YoutubeTranscriptError)` - An error if a configured value cannot be used in a header.
+    pub(crate) fn build_headers(
+        config: Option<&TranscriptConfig>,
+    ) -> Result<HeaderMap, YoutubeTranscriptError> {
+        let mut headers = HeaderMap::new();
+        let user_agent = config
+            .and_then(|c| c.user_agent.as_deref())
+            .unwrap_or(USER_AGENT_STR);
+        let user_agent = HeaderValue::from_str(user_agent)
+            .map_err(|_| YoutubeTranscriptError::InvalidUserAgent(user_agent.to_string()))?;
+        headers.insert("User-Agent", user_agent);
+        if let Some(lang) = config.and_then(|c| c.interface_language()) {
+            let invalid = || YoutubeTranscriptError::InvalidLanguageCode(lang.to_string());
+            HeaderValue::from_str(lang).map_err(|_| invalid())?;
+            // A code that is not a language tag, e.g. `en US`, is still usable to select a
+            // track, so only the header is skipped.
+            let tag = lang.trim();
+            if is_language_tag(tag) {
+                let value = HeaderValue::from_str(tag).map_err(|_| invalid())?;
+                headers.insert("Accept-Language", value);
+            }
+        }
+        let consent = config
+            .and_then(|c| c.consent_cookie.as_deref())
+            .unwrap_or(DEFAULT_CONSENT_COOKIE);
+        let cookie = HeaderValue::from_str(&format!("CONSENT={}", consent))
+            .map_err(|_| YoutubeTranscriptError::InvalidConsentCookie(consent.to_string()))?;
+        headers.insert("Cookie", cookie);
+        for (name, value) in config.map(|c| c.headers.as_slice()).unwrap_or_default() {
+            let invalid = || YoutubeTranscriptError::InvalidHeader(name.to_string());
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?;
+            let header_value = HeaderValue::from_str(value).map_err(|_| invalid())?;
+            headers.insert(header_name, header_value);
+        }
+        Ok(headers)
+    }
+
+    /// Fetches the video page and extracts the caption tracks listed in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client used for the request.
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `headers` - The headers sent with the request.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CaptionTracks)` - The raw caption tracks from the video page.
+    /// * `Err(YoutubeTranscriptError)` - An error if the caption tracks cannot be retrieved.
+    async fn fetch_caption_tracks(
+        client: &Client,
+        video_id: &VideoId,
+        headers: &HeaderMap,
+        config: Option<&TranscriptConfig>,
+    ) -> Result<CaptionTracks, YoutubeTranscriptError> {
+        let video_page_body = Self::fetch_video_page(client, video_id, headers, config).await?;
+        Self::parse_caption_tracks(&video_page_body, config, video_id.as_str())
+    }
+
+    /// Fetches the HTML content of the video page.
+    ///
+    /// With `Backend::InnerTube`, the player response is requested from the InnerTube API
+    /// instead. It is JSON with the same fields as the player response embedded in the page, so
+    /// both are parsed alike.
+    ///
+    /// When `retry_on_captcha` is set in the config, a captcha page is fetched again with
+    /// exponential backoff, up to `max_retries` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client used for the request.
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `headers` - The headers sent with the request.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The HTML content of the video page, or the InnerTube player response.
+    /// * `Err(YoutubeTranscriptError)` - An error if the page cannot be fetched.
+    async fn fetch_video_page(
+        client: &Client,
+        video_id: &VideoId,
+        headers: &HeaderMap,
+        config: Option<&TranscriptConfig>,
+    ) -> Result<String, YoutubeTranscriptError> {
+        // Step 1: Construct the request for the configured backend
+        let (video_page_url, request_body) = Self::video_page_request(video_id, config);
+        let video_id = video_id.as_str();
+
+        // Step 2: Fetch the video page content, retrying captcha pages if configured
+        let mut attempt = 0;
+        loop {
+            let video_page_response = Self::send_request(
+                client,
+                &video_page_url,
+                request_body.as_ref(),
+                headers,
+                config,
+                video_id,
+            )
+            .await?;
+            let video_page_body = Self::read_body(video_page_response, config, video_id).await?;
+            let Some(delay) = Self::captcha_retry_delay(&video_page_body, attempt, config) else {
+                return Ok(video_page_body);
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Decides whether a fetched video page is a captcha page to fetch again.
+    ///
+    /// Captcha pages are only fetched again when `retry_on_captcha` is set in the config, up to
+    /// `max_retries` times, and are never detected when `skip_captcha_detection` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_page_body` - The HTML content of the video page.
+    /// * `attempt` - The number of times the page was fetched before.
+    /// * `config` - An optional `TranscriptConfig` providing the retry options.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Duration>` - The delay before fetching the page again, or `None` to use it as is.
+    pub(crate) fn captcha_retry_delay(
+        video_page_body: &str,
+        attempt: u32,
+        config: Option<&TranscriptConfig>,
+    ) -> Option<Duration> {
+        let captcha_retries = match config {
+            Some(config) if config.retry_on_captcha => config.max_retries,
+            _ => 0,
+        };
+        if attempt == captcha_retries || !Self::detects_captcha(video_page_body, config) {
+            return None;
+        }
+        Some(Self::retry_delay(config, attempt))
+    }
+
+    /// Constructs the request that retrieves the player response for the configured backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `config` - An optional `TranscriptConfig` providing the backend and base URL of YouTube.
+    ///
+    /// # Returns
+    ///
+    /// * `(String, Option<serde_json::Value>)` - The URL to request, and the JSON body to post for
+    ///   `Backend::InnerTube` or `None` to get the watch page.
+    pub(crate) fn video_page_request(
+        video_id: &VideoId,
+        config: Option<&TranscriptConfig>,
+    ) -> (String, Option<serde_json::Value>) {
+        if config.map_or(Backend::Html, |c| c.backend) == Backend::Html {
+            return (Self::video_page_url(video_id, config), None);
+        }
+
+        let base_url = config
+            .and_then(|c| c.base_url.as_deref())
+            .unwrap_or(DEFAULT_BASE_URL);
+        let url = format!(
+            "{}/youtubei/v1/player?prettyPrint=false",
+            base_url.trim_end_matches('/')
+        );
+        // The interface language only affects localized strings, not the caption tracks listed.
+        let hl = config.and_then(|c| c.interface_language()).unwrap_or("en");
+        let body = serde_json::json!({
+            "context": {
+                "client": {
+                    "clientName": INNERTUBE_CLIENT_NAME,
+                    "clientVersion": INNERTUBE_CLIENT_VERSION,
+                    "hl": hl,
+                }
+            },
+            "videoId": video_id.as_str(),
+        });
+        (url, Some(body))
+    }
+
+    /// Constructs the URL of the video page for a given YouTube video ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The ID of the YouTube video.
+    /// * `config` - An optional `TranscriptConfig` providing the base URL of YouTube.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The URL of the video page.
+    pub(crate) fn video_page_url(video_id: &VideoId, config: Option<&TranscriptConfig>) -> String {
+        let base_url = config
+            .and_then(|c| c.base_url.as_deref())
+            .unwrap_or(DEFAULT_BASE_URL);
+        format!("{}/watch?v={}", base_url.trim_end_matches('/'), video_id)
+    }
+
+    /// Sends a GET request, or a POST request with a JSON body, applying the request options
+    /// from the config.
+    ///
+    /// Network errors and `5xx`/`429` responses are retried with exponential backoff when
+    /// `max_retries` is set in the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client used for the request.
+    /// * `url` - The URL to request.
+    /// * `body` - The JSON body to post, or `None` to send a GET request.
+    /// * `headers` - The headers sent with the request.
+    /// * `config` - An optional `TranscriptConfig` providing request options such as the timeout.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Response)` - The response if the request was sent successfully.
+    /// * `Err(YoutubeTranscriptError)` - An error if the request failed or all retries were exhausted.
+    async fn send_request(
+        client: &Client,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        headers: &HeaderMap,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> Result<Response, YoutubeTranscriptError> {
+        let mut attempt = 0;
+        loop {
+            let mut request = match body {
+                Some(body) => client.post(url).json(body),
+                None => client.get(url),
+            };
+            request = request.headers(headers.clone());
+            if let Some(timeout) = config.and_then(|c| c.timeout) {
+                request = request.timeout(timeout);
+            }
+            let result = request.send().await;
+
+            match Self::retry_request(result, Response::status, attempt, config, video_id) {
+                ControlFlow::Break(result) => return result,
+                ControlFlow::Continue(delay) => tokio::time::sleep(delay).await,
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Decides whether an attempt of a request is retried.
+    ///
+    /// Network errors and `5xx`/`429` responses are retried with exponential backoff, up to
+    /// `max_retries` times from the config. This is shared by the async and the blocking client.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The response or error of the attempt.
+    /// * `status` - Returns the status of a response.
+    /// * `attempt` - The number of attempts made before this one.
+    /// * `config` - An optional `TranscriptConfig` providing the retry options.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `ControlFlow::Continue(Duration)` - The delay to wait before the next attempt.
+    /// * `ControlFlow::Break(Result<R, YoutubeTranscriptError>)` - The outcome of the request.
+    pub(crate) fn retry_request<R>(
+        result: Result<R, reqwest::Error>,
+        status: fn(&R) -> StatusCode,
+        attempt: u32,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> ControlFlow<Result<R, YoutubeTranscriptError>, Duration> {
+        let max_retries = config.map_or(0, |c| c.max_retries);
+        let retryable = match &result {
+            Ok(response) => Self::is_retryable_status(status(response)),
+            Err(_) => true,
+        };
+        if !retryable || max_retries == 0 {
+            return ControlFlow::Break(result.map_err(|e| Self::request_error(e, video_id)));
+        }
+        if attempt == max_retries {
+            let result = result.map(|response| status(&response));
+            return ControlFlow::Break(Err(Self::retries_exhausted(result, attempt + 1, video_id)));
+        }
+        ControlFlow::Continue(Self::retry_delay(config, attempt))
+    }
+
+    /// Builds the error returned once every attempt of a request has failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The status or error of the last attempt, which is preserved in the error.
+    /// * `attempts` - The total number of attempts made.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `YoutubeTranscriptError` - A `RetriesExhausted` error describing the last attempt.
+    pub(crate) fn retries_exhausted(
+        result: Result<StatusCode, reqwest::Error>,
+        attempts: u32,
+        video_id: &str,
+    ) -> YoutubeTranscriptError {
+        let (last_status, last_error) = match result {
+            Ok(status) => (Some(status.as_u16()), None),
+            Err(error) => (None, Some(error)),
+        };
+        YoutubeTranscriptError::RetriesExhausted {
+            video_id: video_id.to_string(),
+            attempts,
+            last_status,
+            last_error,
+        }
+    }
+
+    /// Reads the body of a response as text, enforcing `max_body_bytes` from the config.
+    ///
+    /// When a limit is set, the body is read chunk by chunk and reading stops as soon as
+    /// the limit is exceeded, so oversized responses are never held in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response whose body is read.
+    /// * `config` - An optional `TranscriptConfig` providing the body size limit.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The response body.
+    /// * `Err(YoutubeTranscriptError)` - An error if the body cannot be read or exceeds the limit.
+    async fn read_body(
+        mut response: Response,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> Result<String, YoutubeTranscriptError> {
+        let Some(limit) = config.and_then(|c| c.max_body_bytes) else {
+            return response
+                .text()
+                .await
+                .map_err(|e| Self::request_error(e, video_id));
+        };
+
+        let mut body = LimitedBody::new(limit, response.content_length(), video_id)?;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Self::request_error(e, video_id))?
+        {
+            if !body.push(&chunk) {
+                return Err(Self::response_too_large(video_id, limit));
+            }
+        }
+        Ok(body.into_text())
+    }
+
+    /// Builds the error returned when a response body exceeds `max_body_bytes`.
+    pub(crate) fn response_too_large(video_id: &str, limit: usize) -> YoutubeTranscriptError {
+        YoutubeTranscriptError::ResponseTooLarge {
+            video_id: video_id.to_string(),
+            limit,
+        }
+    }
+
+    /// Returns whether a response status indicates a transient failure worth retrying.
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    /// Computes the exponential backoff delay before the retry following `attempt`.
+    pub(crate) fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+        base_delay.saturating_mul(2u32.saturating_pow(attempt))
+    }
+
+    /// Computes the delay before the retry following `attempt`, from the `retry_delay` of the config.
+    fn retry_delay(config: Option<&TranscriptConfig>, attempt: u32) -> Duration {
+        let base_delay = config
+            .and_then(|c| c.retry_delay)
+            .unwrap_or(DEFAULT_RETRY_DELAY);
+        Self::backoff_delay(base_delay, attempt)
+    }
+
+    /// Maps a request error to a `YoutubeTranscriptError`.
+    ///
+    /// Timeouts are reported as `YoutubeTranscriptError::Timeout`; any other error is reported
+    /// as `YoutubeTranscriptError::NetworkError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The underlying request error.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `YoutubeTranscriptError` - The mapped error.
+    pub(crate) fn request_error(error: reqwest::Error, video_id: &str) -> YoutubeTranscriptError {
+        if error.is_timeout() {
+            YoutubeTranscriptError::Timeout(video_id.to_string(), error)
+        } else {
+            YoutubeTranscriptError::NetworkError(error)
+        }
+    }
+
+    /// Extracts the caption tracks from the HTML content of a video page.
+    ///
+    /// The tracks keep the order listed on the page, along with the index of the video's default
+    /// track, as determined by `default_caption_track_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_page_body` - The HTML content of the video page.
+    /// * `config` - An optional `TranscriptConfig` specifying whether to detect captcha pages.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CaptionTracks)` - The raw caption tracks from the video page.
+    /// * `Err(YoutubeTranscriptError)` - An error if the page contains no usable captions.
+    pub(crate) fn parse_caption_tracks(
+        video_page_body: &str,
+        config: Option<&TranscriptConfig>,
+        video_id: &str,
+    ) -> Result<CaptionTracks, YoutubeTranscriptError> {
+        // Step 1: Handle cases where captions are not found
+        if !video_page_body.contains("\"captions\":") {
+            if video_page_body.contains("action=\"https://consent.youtube.com") {
+                return Err(YoutubeTranscriptError::ConsentRequired(
+                    video_id.to_string(),
+                ));
+            }
+            if Self::detects_captcha(video_page_body, config) {
+                return Err(YoutubeTranscriptError::TooManyRequests);
+            }
+            let Some(status) = extract_json_after(video_page_body, "\"playabilityStatus\":") else {
+                return Err(Self::video_unavailable(None, video_id));
+            };
+            if Self::is_age_restricted(&status) {
+                return Err(YoutubeTranscriptError::AgeRestricted(video_id.to_string()));
+            }
+            if Self::is_members_only(&status) {
+                return Err(YoutubeTranscriptError::MembersOnly(video_id.to_string()));
+            }
+            if status
+                .get("status")
+                .and_then(|s| s.as_str())
+                .is_some_and(|s| s != "OK")
+            {
+                return Err(Self::video_unavailable(Some(&status), video_id));
+            }
+            return Err(YoutubeTranscriptError::TranscriptDisabled(
+                video_id.to_string(),
+            ));
+        }
+
+        // Step 2: Parse the captions JSON, which ends where its object closes, regardless of the
+        // fields that follow it
+        let marker = "\"captions\":";
+        let rest = &video_page_body[video_page_body.find(marker).unwrap_or(0) + marker.len()..];
+        let captions_json = match extract_balanced_json(rest, "") {
+            Some(captions_json) => captions_json,
+            None => {
+                // An unbalanced object on a truncated page is reported as such, detected by the
+                // missing `lengthSeconds` that the end of a complete page carries. Otherwise, it is
+                // parsed up to the end of the page so the JSON error points at the problem.
+                let length_seconds = extract_json_after(video_page_body, "\"videoDetails\":")
+                    .and_then(|details| {
+                        details.get("lengthSeconds")?.as_str()?.parse::<u64>().ok()
+                    });
+                if length_seconds.is_none() {
+                    return Err(YoutubeTranscriptError::TranscriptParseFailed(
+                        video_id.to_string(),
+                    ));
+                }
+                rest
+            }
+        };
+        let captions: serde_json::Value = serde_json::from_str(captions_json)
+            .map_err(|e| YoutubeTranscriptError::CaptionsParseFailed(video_id.to_string(), e))?;
+
+        // Step 3: Extract player captions renderer
+        let player_captions_renderer = captions.get("playerCaptionsTracklistRenderer").ok_or(
+            YoutubeTranscriptError::TranscriptDisabled(video_id.to_string()),
+        )?;
+
+        // Step 4: Extract caption tracks. A renderer listing no tracks has no transcript either,
+        // rather than one in no requested language.
+        let tracks = player_captions_renderer
+            .get("captionTracks")
+            .and_then(|tracks| tracks.as_array())
+            .filter(|tracks| !tracks.is_empty())
+            .cloned()
+            .ok_or(YoutubeTranscriptError::TranscriptNotAvailable(
+                video_id.to_string(),
+            ))?;
+
+        // Step 5: Determine the default track, which wins when no language is requested
+        Ok(CaptionTracks {
+            default_index: Self::default_caption_track_index(player_captions_renderer)
+                .filter(|&index| index < tracks.len()),
+            tracks,
+        })
+    }
+
+    /// Determines the index of the video's default caption track.
+    ///
+    /// This is the `defaultCaptionTrackIndex` of the default audio track, which YouTube sets to the
+    /// track in the video's original language. Without it, the first translatable track is used,
+    /// since tracks that cannot be translated are typically secondary ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_captions_renderer` - The `playerCaptionsTracklistRenderer` of the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The index of the default track, or `None` if no default is discernible.
+    fn default_caption_track_index(player_captions_renderer: &serde_json::Value) -> Option<usize> {
+        let audio_tracks = player_captions_renderer
+            .get("audioTracks")
+            .and_then(|v| v.as_array());
+        let default_audio_track = player_captions_renderer
+            .get("defaultAudioTrackIndex")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize;
+        let default_index = audio_tracks
+            .and_then(|tracks| tracks.get(default_audio_track))
+            .and_then(|track| track.get("defaultCaptionTrackIndex"))
+            .and_then(|v| v.as_u64());
+        if let Some(index) = default_index {
+            return Some(index as usize);
+        }
+
+        player_captions_renderer
+            .get("captionTracks")?
+            .as_array()?
+            .iter()
+            .position(|track| track.get("isTranslatable").and_then(|v| v.as_bool()) == Some(true))
+    }
+
+    /// Checks whether a video page is a captcha challenge, unless the config skips the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_page_body` - The HTML content of the video page.
+    /// * `config` - An optional `TranscriptConfig` specifying whether to detect captcha pages.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the page is a captcha challenge and detection is enabled.
+    fn detects_captcha(video_page_body: &str, config: Option<&TranscriptConfig>) -> bool {
+        !config.is_some_and(|c| c.skip_captcha_detection) && Self::is_captcha_page(video_page_body)
+    }
+
+    /// Checks whether a video page is a captcha challenge rather than the video page.
+    ///
+    /// Pages with a captions section are never treated as captchas. Otherwise, a page is a
+    /// captcha if it contains a recaptcha form, or if its `playabilityStatus` asks the viewer to
+    /// confirm they are not a bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_page_body` - The HTML content of the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the page is a captcha challenge.
+    pub(crate) fn is_captcha_page(video_page_body: &str) -> bool {
+        if video_page_body.contains("\"captions\":") {
+            return false;
+        }
+        if video_page_body.contains("id=\"captcha-form\"")
+            || video_page_body.contains("action=\"/das_captcha\"")
+            || (video_page_body.contains("class=\"g-recaptcha\"")
+                && video_page_body.contains("<form"))
+        {
+            return true;
+        }
+        extract_json_after(video_page_body, "\"playabilityStatus\":")
+            .as_ref()
+            .and_then(|status| status.get("reason"))
+            .and_then(|reason| reason.as_str())
+            .is_some_and(|reason| {
+                let reason = reason.to_lowercase();
+                reason.contains("not a bot") || reason.contains("unusual traffic")
+            })
+    }
+
+    /// Checks whether a video page is an age gate rather than the playable video.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The `playabilityStatus` object of the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the status asks the viewer to confirm their age.
+    fn is_age_restricted(status: &serde_json::Value) -> bool {
+        if status.get("desktopLegacyAgeGateReason").is_some() {
+            return true;
+        }
+        match status.get("status").and_then(|s| s.as_str()) {
+            Some("AGE_CHECK_REQUIRED") | Some("AGE_VERIFICATION_REQUIRED") => true,
+            Some("LOGIN_REQUIRED") => status
+                .get("reason")
+                .and_then(|reason| reason.as_str())
+                .is_some_and(|reason| reason.contains("confirm your age")),
+            _ => false,
+        }
+    }
+
+    /// Checks whether a video page withholds the video because it is for channel members only.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The `playabilityStatus` object of the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the status offers a channel membership to watch the video.
+    fn is_members_only(status: &serde_json::Value) -> bool {
+        if status
+            .pointer("/errorScreen/playerLegacyDesktopYpcOfferRenderer")
+            .is_some()
+        {
+            return true;
+        }
+        status
+            .get("reason")
+            .and_then(|reason| reason.as_str())
+            .is_some_and(|reason| {
+                reason.contains("members-only") || reason.contains("Join this channel")
+            })
+    }
+
+    /// Builds a `VideoUnavailable` error carrying the reason YouTube gives for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The `playabilityStatus` object of the video page, if present.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `YoutubeTranscriptError` - The error, with a generic reason if YouTube gives none.
+    fn video_unavailable(
+        status: Option<&serde_json::Value>,
+        video_id: &str,
+    ) -> YoutubeTranscriptError {
+        let reason = status.and_then(|status| {
+            status.get("reason").and_then(|r| r.as_str()).or_else(|| {
+                status
+                    .pointer("/errorScreen/playerErrorMessageRenderer/reason/simpleText")
+                    .and_then(|r| r.as_str())
+            })
+        });
+        YoutubeTranscriptError::VideoUnavailable {
+            video_id: video_id.to_string(),
+            reason: reason
+                .unwrap_or("The video is no longer available")
+                .to_string(),
+        }
+    }
+
+    /// Extracts the video details from the HTML content of a video page.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_page_body` - The HTML content of the video page.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VideoDetails)` - The details of the video.
+    /// * `Err(YoutubeTranscriptError)` - An error if the page contains no video details.
+    fn parse_video_details(
+        video_page_body: &str,
+        video_id: &str,
+    ) -> Result<VideoDetails, YoutubeTranscriptError> {
+        let unavailable = || {
+            let status = extract_json_after(video_page_body, "\"playabilityStatus\":");
+            Self::video_unavailable(status.as_ref(), video_id)
+        };
+        let details =
+            extract_json_after(video_page_body, "\"videoDetails\":").ok_or_else(unavailable)?;
+        let field = |name: &str| details.get(name).and_then(|v| v.as_str()).map(String::from);
+
+        Ok(VideoDetails {
+            video_id: field("videoId").ok_or_else(unavailable)?,
+            title: field("title").unwrap_or_default(),
+            author: field("author").unwrap_or_default(),
+            length_seconds: field("lengthSeconds")
+                .and_then(|length| length.parse().ok())
+                .unwrap_or(0),
+        })
+    }
+
+    /// Parses all entries of a fetched transcript body.
+    ///
+    /// In strict mode, an empty transcript, a skipped entry, or a missing language code is an
+    /// error as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `transcript` - The fetched transcript body.
+    /// * `video_id` - The YouTube video URL or ID, used in error messages.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TranscriptResponse>)` - The parsed transcript entries.
+    /// * `Err(YoutubeTranscriptError)` - An error if a non-empty body yields no entries.
+    pub(crate) fn parse_transcript(
+        transcript: TranscriptBody,
+        video_id: &str,
+    ) -> Result<Vec<TranscriptResponse>, YoutubeTranscriptError> {
+        let is_empty = transcript.body.trim().is_empty();
+        let strict = transcript.strict;
+        let missing_lang = transcript.lang.is_empty();
+
+        let mut iter = TranscriptIter::new(transcript);
+        let entries: Vec<TranscriptResponse> = iter.by_ref().collect();
+        // A non-empty body without entries did not match any known format, which usually
+        // means the format changed.
+        let failed = entries.is_empty() && !is_empty;
+        let anomaly = entries.is_empty() || iter.skipped > 0 || missing_lang;
+        if failed || (strict && anomaly) {
+            return Err(YoutubeTranscriptError::TranscriptParseFailed(
+                video_id.to_string(),
+            ));
+        }
+        Ok(entries)
+    }
+
+    /// Interprets the result of extracting caption tracks as whether any transcript exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `caption_tracks` - The result of extracting the caption tracks from the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether at least one caption track exists.
+    /// * `Err(YoutubeTranscriptError)` - The extraction error if it is unrelated to captions.
+    fn has_caption_tracks(
+        caption_tracks: Result<CaptionTracks, YoutubeTranscriptError>,
+    ) -> Result<bool, YoutubeTranscriptError> {
+        match caption_tracks {
+            Ok(tracks) => Ok(!tracks.is_empty()),
+            Err(YoutubeTranscriptError::TranscriptDisabled(_))
+            | Err(YoutubeTranscriptError::TranscriptNotAvailable(_)) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Converts a raw caption track into a `TranscriptInfo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - A caption track listed on the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<TranscriptInfo>` - The track description, or `None` if the track lacks a language code or URL.
+    fn parse_track_info(track: &serde_json::Value) -> Option<TranscriptInfo> {
+        Some(TranscriptInfo {
+            language_code: track.get("languageCode")?.as_str()?.to_string(),
+            language_name: Self::track_name(track),
+            is_generated: Self::is_generated_track(track),
+            base_url: track.get("baseUrl")?.as_str()?.to_string(),
+        })
+    }
+
+    /// Extracts the human-readable language name of a caption track.
+    ///
+    /// YouTube provides the name either as `simpleText` or as a list of text `runs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - A caption track listed on the video page.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The language name, or `None` if the track has no name.
+    fn track_name(track: &serde_json::Value) -> Option<String> {
+        let name = track.get("name")?;
+        if let Some(text) = name.get("simpleText").and_then(|text| text.as_str()) {
+            return Some(text.to_string());
+        }
+        let text: String = name
+            .get("runs")?
+            .as_array()?
+            .iter()
+            .filter_map(|run| run.get("text").and_then(|text| text.as_str()))
+            .collect();
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Returns whether a raw caption track was automatically generated by YouTube.
+    fn is_generated_track(track: &serde_json::Value) -> bool {
+        track.get("kind").and_then(|kind| kind.as_str()) == Some("asr")
+    }
+
+    /// Resolves the language code reported on transcript entries.
+    ///
+    /// The language of the selected track takes precedence, followed by the first requested language.
+    /// An empty string is returned if neither is known.
+    fn resolve_language(
+        track: Option<&serde_json::Value>,
+        config: Option<&TranscriptConfig>,
+    ) -> String {
+        track
+            .and_then(|track| track.get("languageCode"))
+            .and_then(|code| code.as_str())
+            .or_else(|| config.and_then(|c| c.requested_languages().first().copied()))
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// Selects the caption track matching the requested languages.
+    ///
+    /// Requested languages are tried in order, and the first language with a matching track wins.
+    /// Language codes are compared case-insensitively, treating `_` as `-`. If `language_fallback`
+    /// is set and no track matches exactly, tracks sharing the primary subtag are considered.
+    /// When no language is requested, all available tracks are considered, with the video's default
+    /// track first (see `default_caption_track_index`). Among the candidates, the first is selected,
+    /// unless `prefer_manual` is set and a manually authored candidate exists.
+    /// If `generated_only` is set, only auto-generated candidates are considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `caption_tracks` - The caption tracks listed on the video page.
+    /// * `config` - An optional `TranscriptConfig` specifying the desired languages.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&serde_json::Value>` - The selected caption track, if any matches.
+    fn select_caption_track<'a>(
+        caption_tracks: &'a CaptionTracks,
+        config: Option<&TranscriptConfig>,
+    ) -> Option<&'a serde_json::Value> {
+        let requested_langs = config.map(|c| c.requested_languages()).unwrap_or_default();
+        if requested_langs.is_empty() {
+            let mut candidates: Vec<_> = caption_tracks.iter().collect();
+            if let Some(index) = caption_tracks.default_index {
+                let track = candidates.remove(index);
+                candidates.insert(0, track);
+            }
+            return Self::pick_caption_track(&candidates, config);
+        }
+
+        let find_track = |matches: fn(&str, &str) -> bool| {
+            requested_langs.iter().find_map(|lang| {
+                let lang = normalize_language_code(lang);
+                let candidates: Vec<_> = caption_tracks
+                    .iter()
+                    .filter(|track| {
+                        track
+                            .get("languageCode")
+                            .and_then(|v| v.as_str())
+                            .is_some_and(|code| matches(&normalize_language_code(code), &lang))
+                    })
+                    .collect();
+                Self::pick_caption_track(&candidates, config)
+            })
+        };
+
+        // Step 1: Look for an exact match on any requested language
+        let track = find_track(|code, lang| code == lang);
+        if track.is_some() || !config.is_some_and(|c| c.language_fallback) {
+            return track;
+        }
+
+        // Step 2: Fall back to a match on the primary subtag, e.g. `en` for `en-GB`
+        find_track(|code, lang| primary_subtag(code) == primary_subtag(lang))
+    }
+
+    /// Picks one track among candidates sharing a language, honoring `generated_only` and
+    /// `prefer_manual`.
+    fn pick_caption_track<'a>(
+        candidates: &[&'a serde_json::Value],
+        config: Option<&TranscriptConfig>,
+    ) -> Option<&'a serde_json::Value> {
+        if config.is_some_and(|c| c.generated_only) {
+            return candidates
+                .iter()
+                .find(|track| Self::is_generated_track(track))
+                .copied();
+        }
+        if config.is_some_and(|c| c.prefer_manual) {
+            if let Some(manual) = candidates
+                .iter()
+                .find(|track| !Self::is_generated_track(track))
+            {
+                return Some(manual);
+            }
+        }
+        candidates.first().copied()
+    }
+
+    /// Parses a YouTube URL or video ID into the video ID and the start time it links to.
+    ///
+    /// The start time is read from a `t` or `start` parameter in the query or fragment, as in
+    /// `https://youtu.be/dQw4w9WgXcQ?t=95` or `...#t=1m35s`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A string slice representing the YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VideoUrl)` - The video ID and the start time, if any.
+    /// * `Err(YoutubeTranscriptError)` - An error if the video ID cannot be retrieved.
+    pub fn parse_video_url(url: &str) -> Result<VideoUrl, YoutubeTranscriptError> {
+        let id = Self::retrieve_video_id(url)?;
+        let start = url
+            .trim()
+            .trim_end_matches('>')
+            .split(['?', '#', '&'])
+            .skip(1)
+            .filter_map(|param| param.split_once('='))
+            .find(|(key, _)| *key == "t" || *key == "start")
+            .and_then(|(_, value)| parse_time_offset(value));
+        Ok(VideoUrl { id, start })
+    }
+
+    /// Checks whether a string is a recognizable YouTube video URL or ID.
+    ///
+    /// This applies the same recognition rules as `retrieve_video_id` without making any request,
+    /// so it is cheap enough for validating user input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A string slice that may be a YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether a video ID can be retrieved from `input`.
+    pub fn is_valid_video_input(input: &str) -> bool {
+        Self::retrieve_video_id(input).is_ok()
+    }
+
+    /// Retrieves the video ID from a given YouTube URL or string.
+    ///
+    /// Surrounding whitespace and angle brackets (as added by some chat apps) are ignored,
+    /// as are query parameters trailing a bare video ID. No request is made, so this can be
+    /// used to validate input or derive cache keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The video ID if successfully retrieved.
+    /// * `Err(YoutubeTranscriptError)` - An error if the video ID cannot be retrieved.
+    pub fn retrieve_video_id(video_id: &str) -> Result<String, YoutubeTranscriptError> {
+        let video_id = video_id
+            .trim()
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .trim();
+        if VideoId::is_well_formed(video_id) {
+            return Ok(video_id.to_string());
+        }
+        if let Some(caps) = YOUTUBE_REGEX.captures(video_id) {
+            if let Some(matched) = caps.get(1) {
+                return Ok(matched.as_str().to_string());
+            }
+        }
+        // A bare video ID may still carry trailing parameters, e.g. `dQw4w9WgXcQ&t=10`.
+        let bare_id = video_id.split(['&', '?', '#']).next().unwrap_or_default();
+        if VideoId::is_well_formed(bare_id) {
+            return Ok(bare_id.to_string());
+        }
+        Err(YoutubeTranscriptError::InvalidVideoId)
+    }
+
+    /// Retrieves the video ID from a given YouTube URL or string as a validated `VideoId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - A string slice representing the YouTube video URL or ID.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VideoId)` - The video ID if successfully retrieved.
+    /// * `Err(YoutubeTranscriptError)` - An error if the video ID cannot be retrieved.
+    pub(crate) fn parse_video_id(video_id: &str) -> Result<VideoId, YoutubeTranscriptError> {
+        VideoId::new(Self::retrieve_video_id(video_id)?)
+    }
+}
+
+/// The client options of a `reqwest` client builder.
+///
+/// It is implemented by both the async and the blocking builder, so that `configure_client`
+/// applies the config to either.
+pub(crate) trait ClientOptions: Sized {
+    /// Enables transparent gzip and brotli decoding of responses.
+    fn decompress(self) -> Self;
+    /// Sends all requests through a proxy.
+    fn proxy(self, proxy: Proxy) -> Self;
+    /// Sets the maximum number of idle connections kept open per host.
+    fn pool_max_idle_per_host(self, max_idle: usize) -> Self;
+    /// Sets how long an idle connection is kept open for reuse.
+    fn pool_idle_timeout(self, timeout: Duration) -> Self;
+    /// Sets the interval of TCP keep-alive probes.
+    fn tcp_keepalive(self, interval: Duration) -> Self;
+}
+
+impl ClientOptions for reqwest::ClientBuilder {
+    fn decompress(self) -> Self {
+        self.gzip(true).brotli(true)
+    }
+
+    fn proxy(self, proxy: Proxy) -> Self {
+        self.proxy(proxy)
+    }
+
+    fn pool_max_idle_per_host(self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host(max_idle)
+    }
+
+    fn pool_idle_timeout(self, timeout: Duration) -> Self {
+        self.pool_idle_timeout(timeout)
+    }
+
+    fn tcp_keepalive(self, interval: Duration) -> Self {
+        self.tcp_keepalive(interval)
+    }
+}
+
+/// A response body being read, which holds at most `limit` bytes.
+pub(crate) struct LimitedBody {
+    /// The bytes read so far.
+    body: Vec<u8>,
+    /// The maximum number of bytes accepted.
+    limit: usize,
+    /// Whether a chunk was rejected for exceeding the limit.
+    pub(crate) exceeded: bool,
+}
+
+impl LimitedBody {
+    /// Starts reading a body, which is rejected up front if its declared length exceeds `limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of bytes accepted.
+    /// * `content_length` - The length of the body declared by the response, if any.
+    /// * `video_id` - A string slice representing the YouTube video URL or ID, used in errors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LimitedBody)` - The empty body.
+    /// * `Err(YoutubeTranscriptError)` - A `ResponseTooLarge` error if the declared length exceeds the limit.
+    pub(crate) fn new(
+        limit: usize,
+        content_length: Option<u64>,
+        video_id: &str,
+    ) -> Result<Self, YoutubeTranscriptError> {
+        if content_length.is_some_and(|length| length > limit as u64) {
+            return Err(YoutubeTranscript::response_too_large(video_id, limit));
+        }
+        Ok(Self {
+            body: Vec::new(),
+            limit,
+            exceeded: false,
+        })
+    }
+
+    /// Appends a chunk of the body, returning `false` without appending it if the limit is exceeded.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> bool {
+        self.exceeded = self.body.len() + chunk.len() > self.limit;
+        if !self.exceeded {
+            self.body.extend_from_slice(chunk);
+        }
+        !self.exceeded
+    }
+
+    /// Returns the body read so far as text.
+    pub(crate) fn into_text(self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Checks whether a language code looks like a BCP 47 tag, e.g. `en`, `en-US`, or `zh_Hant`.
+///
+/// # Arguments
+///
+/// * `code` - The language code to check.
+///
+/// # Returns
+///
+/// * `bool` - Whether the code consists of 1 to 8 ASCII alphanumeric subtags separated by `-` or `_`.
+fn is_language_tag(code: &str) -> bool {
+    code.split(['-', '_']).all(|subtag| {
+        (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Normalizes a language code for comparison, e.g. `en_US` and `EN-us` both become `en-us`.
+///
+/// # Arguments
+///
+/// * `code` - The language code to normalize.
+///
+/// # Returns
+///
+/// * `String` - The lowercased code with `_` replaced by `-`.
+fn normalize_language_code(code: &str) -> String {
+    code.trim().to_ascii_lowercase().replace('_', "-")
+}
+
+/// Returns the primary subtag of a normalized language code, e.g. `en` for `en-gb`.
+fn primary_subtag(code: &str) -> &str {
+    code.split('-').next().unwrap_or(code)
+}
+
+/// Parses a YouTube time offset such as `95`, `95s`, or `1h2m3s` into seconds.
+///
+/// # Arguments
+///
+/// * `value` - The value of a `t` or `start` URL parameter.
+///
+/// # Returns
+///
+/// * `Option<f64>` - The offset in seconds, or `None` if the value is malformed.
+fn parse_time_offset(value: &str) -> Option<f64> {
+    if let Ok(seconds) = value.parse::<f64>() {
+        return (seconds >= 0.0).then_some(seconds);
+    }
+    let mut total = 0.0;
+    let mut digits = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' | '.' => digits.push(c),
+            'h' | 'm' | 's' => {
+                let amount: f64 = digits.parse().ok()?;
+                let unit = match c {
+                    'h' => 3600.0,
+                    'm' => 60.0,
+                    _ => 1.0,
+                };
+                total += amount * unit;
+                digits.clear();
+            }
+            _ => return None,
+        }
+    }
+    digits.is_empty().then_some(total)
+}
+
+/// Sets a query parameter on a URL, replacing any existing value for the same key.
+///
+/// # Arguments
+///
+/// * `url` - The URL to modify.
+/// * `key` - The name of the query parameter.
+/// * `value` - The value of the query parameter.
+///
+/// # Returns
+///
+/// * `Option<String>` - The modified URL, or `None` if `url` is not a valid URL.
+fn with_query_param(url: &str, key: &str, value: &str) -> Option<String> {
+    let mut url = Url::parse(url).ok()?;
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != key)
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair(key, value);
+    Some(url.into())
+}
+
+/// Parses the JSON object or array that immediately follows `marker` in `body`.
+///
+/// Any content after the end of the JSON value is ignored.
+///
+/// # Arguments
+///
+/// * `body` - The text to search, typically the HTML content of a video page.
+/// * `marker` - The text that directly precedes the JSON value.
+///
+/// # Returns
+///
+/// * `Option<serde_json::Value>` - The parsed value, or `None` if the marker is missing or the value is malformed.
+fn extract_json_after(body: &str, marker: &str) -> Option<serde_json::Value> {
+    serde_json::from_str(extract_balanced_json(body, marker)?).ok()
+}
+
+/// Returns the text of the JSON object or array that immediately follows `marker` in `body`.
+///
+/// Braces and brackets are matched while skipping over string literals, so the value ends
+/// exactly where it closes, whatever follows it on the page.
+///
+/// # Arguments
+///
+/// * `body` - The text to search, typically the HTML content of a video page.
+/// * `marker` - The text that directly precedes the JSON value.
+///
+/// # Returns
+///
+/// * `Option<&str>` - The balanced JSON text, or `None` if the marker is missing, is not followed
+///   by an object or array, or the value is never closed.
+fn extract_balanced_json<'a>(body: &'a str, marker: &str) -> Option<&'a str> {
+    let start = body.find(marker)? + marker.len();
+    let value = body[start..].trim_start();
+    if !value.starts_with(['{', '[']) {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&value[..=i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retrieve_video_id_from_url() {
+        let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_from_shorts_url() {
+        let url = "https://www.youtube.com/shorts/dQw4w9WgXcQ";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_from_nocookie_embed_url() {
+        let url = "https://www.youtube-nocookie.com/embed/dQw4w9WgXcQ?start=30";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_from_live_url() {
+        let url = "https://www.youtube.com/live/dQw4w9WgXcQ?si=abc";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_with_surrounding_whitespace() {
+        let result = YoutubeTranscript::retrieve_video_id(" dQw4w9WgXcQ ");
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_with_trailing_params() {
+        let result = YoutubeTranscript::retrieve_video_id("dQw4w9WgXcQ&t=10");
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_parse_video_url() {
+        let url = "https://youtu.be/dQw4w9WgXcQ?t=95";
+        let video_url = YoutubeTranscript::parse_video_url(url).unwrap();
+        assert_eq!(video_url.id, "dQw4w9WgXcQ");
+        assert_eq!(video_url.start, Some(95.0));
+
+        let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=1m35s";
+        let video_url = YoutubeTranscript::parse_video_url(url).unwrap();
+        assert_eq!(video_url.start, Some(95.0));
+
+        let url = "https://www.youtube.com/embed/dQw4w9WgXcQ?start=30";
+        let video_url = YoutubeTranscript::parse_video_url(url).unwrap();
+        assert_eq!(video_url.start, Some(30.0));
+
+        let video_url = YoutubeTranscript::parse_video_url("dQw4w9WgXcQ").unwrap();
+        assert_eq!(video_url.start, None);
+    }
+
+    #[test]
+    fn test_parse_time_offset() {
+        assert_eq!(parse_time_offset("95"), Some(95.0));
+        assert_eq!(parse_time_offset("95s"), Some(95.0));
+        assert_eq!(parse_time_offset("1h2m3s"), Some(3723.0));
+        assert_eq!(parse_time_offset("1m"), Some(60.0));
+        assert_eq!(parse_time_offset("abc"), None);
+        assert_eq!(parse_time_offset("1m30"), None);
+    }
+
+    #[test]
+    fn test_is_valid_video_input() {
+        assert!(YoutubeTranscript::is_valid_video_input("dQw4w9WgXcQ"));
+        assert!(YoutubeTranscript::is_valid_video_input(
+            "https://youtu.be/dQw4w9WgXcQ"
+        ));
+        assert!(!YoutubeTranscript::is_valid_video_input(
+            "https://www.example.com/watch?v=abc"
+        ));
+        assert!(!YoutubeTranscript::is_valid_video_input(""));
+        assert!(!YoutubeTranscript::is_valid_video_input("hello world"));
+        assert!(!YoutubeTranscript::is_valid_video_input("not a url!!"));
+        assert!(!YoutubeTranscript::is_valid_video_input("https://x.c"));
+    }
+
+    #[test]
+    fn test_retrieve_video_id_from_playlist_url() {
+        let url = " <https://www.youtube.com/watch?v=dQw4w9WgXcQ&list=PLFgquLnL59alCl_2TQvOiD5Vgm1hCaGSI&index=2> ";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert_eq!(result.unwrap(), "dQw4w9WgXcQ");
+    }
+
+    #[test]
+    fn test_retrieve_video_id_from_invalid_url() {
+        let url = "https://www.example.com/watch?v=dQw4w9WgXcQ";
+        let result = YoutubeTranscript::retrieve_video_id(url);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_retrieve_video_id_rejects_invalid_characters() {
+        for input in ["hello world", "not a url!!", "https://x.c"] {
+            let result = YoutubeTranscript::retrieve_video_id(input);
+            assert!(matches!(
+                result,
+                Err(YoutubeTranscriptError::InvalidVideoId)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_select_caption_track_without_lang() {
+        let tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/en" }),
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+        ]);
+        let config = TranscriptConfig {
+            lang: None,
+            ..Default::default()
+        };
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["languageCode"], "en");
+    }
+
+    #[test]
+    fn test_select_caption_track_with_lang() {
+        let tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/en" }),
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+        ]);
+        let config = TranscriptConfig::builder().lang("de").build();
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["languageCode"], "de");
+    }
+
+    #[test]
+    fn test_select_caption_track_with_language_fallback() {
+        let tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+            serde_json::json!({ "languageCode": "en-GB", "baseUrl": "https://example.com/en-GB" }),
+        ]);
+        let config = TranscriptConfig::builder()
+            .languages(["en", "en-US", "en-GB"])
+            .build();
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["languageCode"], "en-GB");
+
+        let config = TranscriptConfig::builder().languages(["fr", "es"]).build();
+        assert!(YoutubeTranscript::select_caption_track(&tracks, Some(&config)).is_none());
+    }
+
+    #[test]
+    fn test_select_caption_track_prefers_manual() {
+        let tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en", "kind": "asr", "baseUrl": "https://example.com/asr" }),
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/manual" }),
+        ]);
+        let config = TranscriptConfig::builder().lang("en").build();
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/asr");
+
+        let config = TranscriptConfig::builder()
+            .lang("en")
+            .prefer_manual(true)
+            .build();
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/manual");
+    }
+
+    #[test]
+    fn test_select_caption_track_generated_only() {
+        let tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/manual" }),
+            serde_json::json!({ "languageCode": "en", "kind": "asr", "baseUrl": "https://example.com/asr" }),
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+        ]);
+        let config = TranscriptConfig::builder()
+            .lang("en")
+            .prefer_manual(true)
+            .generated_only(true)
+            .build();
+        let track = YoutubeTranscript::select_caption_track(&tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/asr");
+
+        let config = TranscriptConfig::builder()
+            .lang("de")
+            .generated_only(true)
+            .build();
+        let result =
+            YoutubeTranscript::resolve_transcript_track(&tracks, Some(&config), "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TranscriptNotAvailableLanguage(..))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_language() {
+        let track =
+            serde_json::json!({ "languageCode": "fr", "baseUrl": "https://example.com/fr" });
+        assert_eq!(
+            YoutubeTranscript::resolve_language(Some(&track), None),
+            "fr"
+        );
+
+        let track = serde_json::json!({ "baseUrl": "https://example.com" });
+        assert_eq!(YoutubeTranscript::resolve_language(Some(&track), None), "");
+        assert_eq!(YoutubeTranscript::resolve_language(None, None), "");
+    }
+
+    #[test]
+    fn test_select_caption_track_normalizes_language_codes() {
+        let caption_tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en-US", "baseUrl": "https://example.com/en-US" }),
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+        ]);
+
+        let config = TranscriptConfig::builder().lang("en_US").build();
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/en-US");
+
+        let config = TranscriptConfig::builder().lang("en-us").build();
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/en-US");
+
+        let config = TranscriptConfig::builder().lang("DE").build();
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/de");
+    }
+
+    #[test]
+    fn test_select_caption_track_falls_back_to_primary_subtag() {
+        let caption_tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "de", "baseUrl": "https://example.com/de" }),
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/en" }),
+        ]);
+
+        let config = TranscriptConfig::builder().lang("en-GB").build();
+        assert!(YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config)).is_none());
+
+        let config = TranscriptConfig::builder()
+            .languages(["fr", "en-GB", "de"])
+            .language_fallback(true)
+            .build();
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/de");
+
+        let config = TranscriptConfig::builder()
+            .languages(["fr", "en-GB"])
+            .language_fallback(true)
+            .build();
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/en");
+    }
+
+    #[test]
+    fn test_parse_track_info() {
+        let track = serde_json::json!({
+            "baseUrl": "https://example.com/en",
+            "name": { "simpleText": "English (auto-generated)" },
+            "languageCode": "en",
+            "kind": "asr"
+        });
+        let info = YoutubeTranscript::parse_track_info(&track).unwrap();
+        assert_eq!(info.language_code, "en");
+        assert_eq!(
+            info.language_name.as_deref(),
+            Some("English (auto-generated)")
+        );
+        assert!(info.is_generated);
+        assert_eq!(info.base_url, "https://example.com/en");
+    }
+
+    #[test]
+    fn test_parse_track_info_with_name_runs() {
+        let track = serde_json::json!({
+            "baseUrl": "https://example.com/de",
+            "name": { "runs": [{ "text": "German" }] },
+            "languageCode": "de"
+        });
+        let info = YoutubeTranscript::parse_track_info(&track).unwrap();
+        assert_eq!(info.language_name.as_deref(), Some("German"));
+    }
+
+    #[test]
+    fn test_track_name() {
+        let track = serde_json::json!({ "name": { "simpleText": "English" } });
+        assert_eq!(
+            YoutubeTranscript::track_name(&track).as_deref(),
+            Some("English")
+        );
+
+        let track = serde_json::json!({
+            "name": { "runs": [{ "text": "English" }, { "text": " (auto-generated)" }] }
+        });
+        assert_eq!(
+            YoutubeTranscript::track_name(&track).as_deref(),
+            Some("English (auto-generated)")
+        );
+
+        let track = serde_json::json!({ "languageCode": "en" });
+        assert!(YoutubeTranscript::track_name(&track).is_none());
+    }
+
+    #[test]
+    fn test_build_client_with_invalid_proxy() {
+        let config = TranscriptConfig::builder().proxy("not a proxy url").build();
+        let result = YoutubeTranscript::build_client(Some(&config));
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidProxy(_))
+        ));
+
+        let config = TranscriptConfig::builder()
+            .proxy("http://127.0.0.1:8080")
+            .build();
+        assert!(YoutubeTranscript::build_client(Some(&config)).is_ok());
+    }
+
+    #[test]
+    fn test_build_client_with_pool_settings() {
+        let config = TranscriptConfig::builder()
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .tcp_keepalive(Duration::from_secs(60))
+            .build();
+        assert!(YoutubeTranscript::build_client(Some(&config)).is_ok());
+    }
+
+    #[test]
+    fn test_build_headers_with_invalid_lang() {
+        let config = TranscriptConfig::builder().lang("en\n").build();
+        let result = YoutubeTranscript::build_headers(Some(&config));
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidLanguageCode(lang)) if lang == "en\n"
+        ));
+    }
+
+    #[test]
+    fn test_build_headers_skips_blank_lang() {
+        let config = TranscriptConfig::builder().lang("  ").build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert!(headers.get("Accept-Language").is_none());
+
+        let config = TranscriptConfig::builder()
+            .lang("")
+            .languages(["en-US"])
+            .build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["Accept-Language"], "en-US");
+
+        let config = TranscriptConfig::builder().lang(" en ").build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["Accept-Language"], "en");
+
+        let config = TranscriptConfig::builder().lang("en US").build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert!(headers.get("Accept-Language").is_none());
+    }
+
+    #[test]
+    fn test_build_headers_uses_page_language() {
+        let config = TranscriptConfig::builder()
+            .lang("ja")
+            .page_language("en")
+            .build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["Accept-Language"], "en");
+
+        let caption_tracks = CaptionTracks::from(vec![
+            serde_json::json!({ "languageCode": "en", "baseUrl": "https://example.com/en" }),
+            serde_json::json!({ "languageCode": "ja", "baseUrl": "https://example.com/ja" }),
+        ]);
+        let track = YoutubeTranscript::select_caption_track(&caption_tracks, Some(&config));
+        assert_eq!(track.unwrap()["baseUrl"], "https://example.com/ja");
+
+        let config = TranscriptConfig::builder().page_language("en").build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["Accept-Language"], "en");
+    }
+
+    #[test]
+    fn test_build_headers_sets_consent_cookie() {
+        let headers = YoutubeTranscript::build_headers(None).unwrap();
+        assert_eq!(headers["Cookie"], "CONSENT=YES+cb");
+
+        let config = TranscriptConfig::builder()
+            .consent_cookie("YES+custom")
+            .build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["Cookie"], "CONSENT=YES+custom");
+    }
+
+    #[test]
+    fn test_build_headers_sets_user_agent() {
+        let headers = YoutubeTranscript::build_headers(None).unwrap();
+        assert_eq!(headers["User-Agent"], USER_AGENT_STR);
+
+        let config = TranscriptConfig::builder()
+            .user_agent("ytranscript-test/1.0")
+            .build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["User-Agent"], "ytranscript-test/1.0");
+
+        let config = TranscriptConfig::builder().user_agent("bad\nagent").build();
+        let result = YoutubeTranscript::build_headers(Some(&config));
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidUserAgent(_))
+        ));
+    }
+
+    #[test]
+    fn test_has_caption_tracks() {
+        let tracks = CaptionTracks::from(vec![serde_json::json!({ "languageCode": "en" })]);
+        assert!(YoutubeTranscript::has_caption_tracks(Ok(tracks)).unwrap());
+        assert!(
+            !YoutubeTranscript::has_caption_tracks(Ok(CaptionTracks::from(Vec::new()))).unwrap()
+        );
+
+        let disabled = YoutubeTranscriptError::TranscriptDisabled("dQw4w9WgXcQ".to_string());
+        assert!(!YoutubeTranscript::has_caption_tracks(Err(disabled)).unwrap());
+
+        let unavailable = YoutubeTranscript::video_unavailable(None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            YoutubeTranscript::has_caption_tracks(Err(unavailable)),
+            Err(YoutubeTranscriptError::VideoUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_build_headers_with_extra_headers() {
+        let config = TranscriptConfig::builder()
+            .header("X-Request-Id", "abc123")
+            .header("user-agent", "custom-agent")
+            .build();
+        let headers = YoutubeTranscript::build_headers(Some(&config)).unwrap();
+        assert_eq!(headers["X-Request-Id"], "abc123");
+        assert_eq!(headers["User-Agent"], "custom-agent");
+        assert_eq!(headers["Cookie"], "CONSENT=YES+cb");
+
+        let config = TranscriptConfig::builder()
+            .header("bad header", "value")
+            .build();
+        let result = YoutubeTranscript::build_headers(Some(&config));
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidHeader(name)) if name == "bad header"
+        ));
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_detects_consent_page() {
+        let body = r#"<form action="https://consent.youtube.com/save" method="POST"></form>"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::ConsentRequired(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_detects_age_restriction() {
+        let body = r#"{"playabilityStatus":{"status":"LOGIN_REQUIRED","reason":"Sign in to confirm your age"}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::AgeRestricted(_))
+        ));
+
+        let body = r#"{"playabilityStatus":{"status":"OK"}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TranscriptDisabled(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_detects_members_only() {
+        let body = r#"{"playabilityStatus":{"status":"LOGIN_REQUIRED","reason":"Join this channel to get access to members-only content like this video, and other exclusive perks."}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::MembersOnly(_))
+        ));
+
+        let body = r#"{"playabilityStatus":{"status":"UNPLAYABLE","errorScreen":{"playerLegacyDesktopYpcOfferRenderer":{}}}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::MembersOnly(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_captcha_page() {
+        let body = r#"<form id="captcha-form" action="index" method="post"><div class="g-recaptcha"></div></form>"#;
+        assert!(YoutubeTranscript::is_captcha_page(body));
+
+        let body = r#"{"playabilityStatus":{"status":"LOGIN_REQUIRED","reason":"Sign in to confirm you’re not a bot"}}"#;
+        assert!(YoutubeTranscript::is_captcha_page(body));
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TooManyRequests)
+        ));
+
+        // A stray recaptcha class without a form, or on a page with captions, is not a captcha.
+        let body = r#"<div class="g-recaptcha"></div>{"playabilityStatus":{"status":"OK"}}"#;
+        assert!(!YoutubeTranscript::is_captcha_page(body));
+        let body = r#"<form><div class="g-recaptcha"></div></form>"captions":{}"#;
+        assert!(!YoutubeTranscript::is_captcha_page(body));
+    }
+
+    #[test]
+    fn test_skip_captcha_detection() {
+        let body = r#"{"playabilityStatus":{"status":"LOGIN_REQUIRED","reason":"Sign in to confirm you’re not a bot"}}"#;
+        let config = TranscriptConfig::builder()
+            .retry_on_captcha(true)
+            .max_retries(2)
+            .build();
+        assert!(YoutubeTranscript::captcha_retry_delay(body, 0, Some(&config)).is_some());
+
+        let config = TranscriptConfig::builder()
+            .retry_on_captcha(true)
+            .max_retries(2)
+            .skip_captcha_detection(true)
+            .build();
+        assert!(YoutubeTranscript::captcha_retry_delay(body, 0, Some(&config)).is_none());
+        let result = YoutubeTranscript::parse_caption_tracks(body, Some(&config), "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::VideoUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_extract_balanced_json() {
+        let body = r#"var p = {"captions": {"name":"a } b","quote":"\"{","list":[1,{"x":2}]}, "videoDetails":{}};"#;
+        assert_eq!(
+            extract_balanced_json(body, "\"captions\":"),
+            Some(r#"{"name":"a } b","quote":"\"{","list":[1,{"x":2}]}"#)
+        );
+        assert_eq!(
+            extract_balanced_json(body, "\"list\":"),
+            Some(r#"[1,{"x":2}]"#)
+        );
+
+        assert_eq!(extract_balanced_json(body, "\"name\":"), None);
+        assert_eq!(extract_balanced_json(body, "\"missing\":"), None);
+        assert_eq!(
+            extract_balanced_json(r#""captions":{"a":{"b":1}"#, "\"captions\":"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_rejects_empty_track_list() {
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[]}},"videoDetails":{"lengthSeconds":"212"}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TranscriptNotAvailable(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_finds_default_track() {
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://example.com/de","languageCode":"de"},{"baseUrl":"https://example.com/en","languageCode":"en"},{"baseUrl":"https://example.com/fr","languageCode":"fr"}],"audioTracks":[{"captionTrackIndices":[0,1,2],"defaultCaptionTrackIndex":1}]}},"videoDetails":{"lengthSeconds":"212"}"#;
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ").unwrap();
+        let codes: Vec<_> = caption_tracks
+            .iter()
+            .map(|track| track["languageCode"].as_str().unwrap())
+            .collect();
+        assert_eq!(codes, ["de", "en", "fr"]);
+        assert_eq!(caption_tracks.default_index, Some(1));
+        let track =
+            YoutubeTranscript::resolve_transcript_track(&caption_tracks, None, "dQw4w9WgXcQ")
+                .unwrap();
+        assert_eq!(track.lang, "en");
+
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://example.com/de","languageCode":"de","isTranslatable":false},{"baseUrl":"https://example.com/en","languageCode":"en","isTranslatable":true}]}},"videoDetails":{"lengthSeconds":"212"}"#;
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(caption_tracks[0]["languageCode"], "de");
+        let track =
+            YoutubeTranscript::resolve_transcript_track(&caption_tracks, None, "dQw4w9WgXcQ")
+                .unwrap();
+        assert_eq!(track.lang, "en");
+
+        // A requested language is still matched against every track, in the listed order.
+        let config = TranscriptConfig::builder().lang("de").build();
+        let track = YoutubeTranscript::resolve_transcript_track(
+            &caption_tracks,
+            Some(&config),
+            "dQw4w9WgXcQ",
+        )
+        .unwrap();
+        assert_eq!(track.lang, "de");
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_with_reordered_fields() {
+        let body = r#"{"videoDetails":{"lengthSeconds":"212"},"captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://example.com/en","languageCode":"en"}]}},"storyboards":{}}"#;
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(caption_tracks[0]["languageCode"], "en");
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_without_video_details() {
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://example.com/en","languageCode":"en"}]}},"storyboards":{}"#;
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(caption_tracks[0]["languageCode"], "en");
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_detects_malformed_page() {
+        let tracks = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://example.com/en","languageCode":"en"}]}}"#;
+
+        let body = format!(r#"{tracks},"videoDetails":{{"lengthSeconds":"212","title":"Never"}}"#);
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(&body, None, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(caption_tracks.len(), 1);
+
+        // A page cut off after complete captions still lists every track.
+        let body = format!(r#"{tracks},"videoDetails":{{"lengthSeconds":"212","tit"#);
+        let caption_tracks =
+            YoutubeTranscript::parse_caption_tracks(&body, None, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(caption_tracks.len(), 1);
+
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTracks":[{"baseUrl":"https://exa"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TranscriptParseFailed(_))
+        ));
+
+        let body = r#""captions":{"playerCaptionsTracklistRenderer":{"captionTra,"videoDetails":{"lengthSeconds":"212"}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error,
+            YoutubeTranscriptError::CaptionsParseFailed(..)
+        ));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_is_retryable_status() {
+        assert!(YoutubeTranscript::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(YoutubeTranscript::is_retryable_status(
+            StatusCode::BAD_GATEWAY
+        ));
+        assert!(!YoutubeTranscript::is_retryable_status(
+            StatusCode::NOT_FOUND
+        ));
+        assert!(!YoutubeTranscript::is_retryable_status(StatusCode::OK));
+    }
+
+    #[test]
+    fn test_retries_exhausted_preserves_last_status() {
+        let error = YoutubeTranscript::retries_exhausted(
+            Ok(StatusCode::SERVICE_UNAVAILABLE),
+            3,
+            "dQw4w9WgXcQ",
+        );
+        assert!(matches!(
+            error,
+            YoutubeTranscriptError::RetriesExhausted {
+                attempts: 3,
+                last_status: Some(503),
+                last_error: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_limited_body() {
+        assert!(matches!(
+            LimitedBody::new(4, Some(5), "dQw4w9WgXcQ"),
+            Err(YoutubeTranscriptError::ResponseTooLarge { limit: 4, .. })
+        ));
+
+        let mut body = LimitedBody::new(4, None, "dQw4w9WgXcQ").unwrap();
+        assert!(body.push(b"abc"));
+        assert!(!body.push(b"de"));
+        assert!(body.exceeded);
+        assert_eq!(body.into_text(), "abc");
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let base = Duration::from_millis(100);
+        assert_eq!(
+            YoutubeTranscript::backoff_delay(base, 0),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            YoutubeTranscript::backoff_delay(base, 1),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            YoutubeTranscript::backoff_delay(base, 3),
+            Duration::from_millis(800)
+        );
+    }
+
+    #[test]
+    fn test_parse_video_details() {
+        let body = r#"var ytInitialPlayerResponse = {"videoDetails":{"videoId":"dQw4w9WgXcQ","title":"Never Gonna Give You Up","lengthSeconds":"212","author":"Rick Astley"},"annotations":[]};"#;
+        let details = YoutubeTranscript::parse_video_details(body, "dQw4w9WgXcQ").unwrap();
+        assert_eq!(details.video_id, "dQw4w9WgXcQ");
+        assert_eq!(details.title, "Never Gonna Give You Up");
+        assert_eq!(details.author, "Rick Astley");
+        assert_eq!(details.length_seconds, 212);
+    }
+
+    #[test]
+    fn test_parse_video_details_missing() {
+        let result = YoutubeTranscript::parse_video_details("<html></html>", "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::VideoUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_caption_tracks_reports_unavailable_reason() {
+        let body =
+            r#"{"playabilityStatus":{"status":"LOGIN_REQUIRED","reason":"This video is private"}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::VideoUnavailable { reason, .. }) if reason == "This video is private"
+        ));
+
+        let body = r#"{"playabilityStatus":{"status":"ERROR","errorScreen":{"playerErrorMessageRenderer":{"reason":{"simpleText":"Video unavailable"}}}}}"#;
+        let result = YoutubeTranscript::parse_caption_tracks(body, None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::VideoUnavailable { reason, .. }) if reason == "Video unavailable"
+        ));
+
+        let result = YoutubeTranscript::parse_caption_tracks("<html></html>", None, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::VideoUnavailable { reason, .. })
+                if reason == "The video is no longer available"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcripts_pairs_results_with_inputs() {
+        let results = YoutubeTranscript::fetch_transcripts(&["invalid", "also invalid"], None)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "invalid");
+        assert_eq!(results[1].0, "also invalid");
+        assert!(results
+            .iter()
+            .all(|(_, result)| matches!(result, Err(YoutubeTranscriptError::InvalidVideoId))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcripts_with_invalid_proxy() {
+        let config = TranscriptConfig::builder().proxy("not a proxy url").build();
+        let result = YoutubeTranscript::fetch_transcripts(&["dQw4w9WgXcQ"], Some(config)).await;
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidProxy(proxy)) if proxy == "not a proxy url"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_with_details_with_invalid_url() {
+        let url = "https://www.example.com/watch?v=dQw4w9WgXcQ";
+        let result = YoutubeTranscript::fetch_transcript_with_details(url, None).await;
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidVideoId)
+        ));
+    }
+
+    /// Builds a player response listing caption tracks served by a mock server.
+    ///
+    /// Each track is given as its language code, its name (empty for none), and whether it is
+    /// auto-generated. Its transcript is served at `{base_url}/api/timedtext?lang={code}`.
+    fn captions_page(base_url: &str, tracks: &[(&str, &str, bool)]) -> String {
+        let caption_tracks: Vec<_> = tracks
+            .iter()
+            .map(|&(code, name, is_generated)| {
+                let mut track = serde_json::json!({
+                    "baseUrl": format!("{base_url}/api/timedtext?lang={code}"),
+                    "languageCode": code,
+                });
+                if !name.is_empty() {
+                    track["name"] = serde_json::json!({ "simpleText": name });
+                }
+                if is_generated {
+                    track["kind"] = "asr".into();
+                }
+                track
+            })
+            .collect();
+        serde_json::json!({
+            "playabilityStatus": { "status": "OK" },
+            "captions": {
+                "playerCaptionsTracklistRenderer": { "captionTracks": caption_tracks },
+            },
+            "videoDetails": { "videoId": "dQw4w9WgXcQ", "lengthSeconds": "212" },
+        })
+        .to_string()
+    }
+
+    /// Builds an `srv1` XML transcript from cues given as start, duration, and raw text.
+    fn transcript_xml(cues: &[(f64, f64, &str)]) -> String {
+        let texts: String = cues
+            .iter()
+            .map(|(start, dur, text)| format!(r#"<text start="{start}" dur="{dur}">{text}</text>"#))
+            .collect();
+        format!("<transcript>{texts}</transcript>")
+    }
+
+    /// Serves the responses built from the server's base URL in order, one per connection.
+    ///
+    /// Returns the base URL of the server, e.g. `http://127.0.0.1:12345`.
+    fn serve_responses(responses: impl FnOnce(&str) -> Vec<String>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses(&base_url);
+        std::thread::spawn(move || {
+            for (stream, body) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                // Drain the body of POST requests, so the connection closes cleanly.
+                let request = String::from_utf8_lossy(&request).into_owned();
+                if let Some((head, body)) = request.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .filter_map(|line| line.split_once(':'))
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    let mut remaining = length.saturating_sub(body.len());
+                    while remaining > 0 {
+                        let read = stream.read(&mut buf).unwrap();
+                        if read == 0 {
+                            break;
+                        }
+                        remaining = remaining.saturating_sub(read);
+                    }
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        base_url
+    }
+
+    #[test]
+    fn test_video_page_url_uses_base_url() {
+        let video_id = VideoId::new("dQw4w9WgXcQ").unwrap();
+        let url = YoutubeTranscript::video_page_url(&video_id, None);
+        assert_eq!(url, "https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+
+        let config = TranscriptConfig::builder()
+            .base_url("http://127.0.0.1:8080/")
+            .build();
+        let url = YoutubeTranscript::video_page_url(&video_id, Some(&config));
+        assert_eq!(url, "http://127.0.0.1:8080/watch?v=dQw4w9WgXcQ");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_from_mock_server() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                format!(
+                    "<script>var ytInitialPlayerResponse = {};</script>",
+                    captions_page(base_url, &[("en", "English", false)])
+                ),
+                transcript_xml(&[(0.5, 1.5, "hello"), (2.0, 3.0, "world")]),
+            ]
+        });
+        let config = TranscriptConfig::builder().base_url(base_url).build();
+
+        let entries = YoutubeTranscript::fetch_transcript("dQw4w9WgXcQ", Some(config))
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].text, "hello");
+        assert_eq!(entries[0].offset, 0.5);
+        assert_eq!(entries[1].text, "world");
+        assert_eq!(entries[1].lang, "en");
+        assert_eq!(entries[1].language_name.as_deref(), Some("English"));
+    }
+
+    #[test]
+    fn test_video_page_request_for_innertube() {
+        let video_id = VideoId::new("dQw4w9WgXcQ").unwrap();
+        let (url, body) = YoutubeTranscript::video_page_request(&video_id, None);
+        assert_eq!(url, "https://www.youtube.com/watch?v=dQw4w9WgXcQ");
+        assert!(body.is_none());
+
+        let config = TranscriptConfig::builder()
+            .backend(Backend::InnerTube)
+            .lang("de")
+            .build();
+        let (url, body) = YoutubeTranscript::video_page_request(&video_id, Some(&config));
+        assert_eq!(
+            url,
+            "https://www.youtube.com/youtubei/v1/player?prettyPrint=false"
+        );
+        let body = body.unwrap();
+        assert_eq!(body["videoId"], "dQw4w9WgXcQ");
+        assert_eq!(body["context"]["client"]["clientName"], "WEB");
+        assert_eq!(body["context"]["client"]["hl"], "de");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_with_innertube_backend() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("en", "", false)]),
+                transcript_xml(&[(0.0, 1.0, "hello")]),
+            ]
+        });
+        let config = TranscriptConfig::builder()
+            .base_url(base_url)
+            .backend(Backend::InnerTube)
+            .build();
+
+        let entries = YoutubeTranscript::fetch_transcript("dQw4w9WgXcQ", Some(config))
+            .await
+            .unwrap();
+        assert_eq!(entries[0].text, "hello");
+        assert_eq!(entries[0].lang, "en");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_text_joins_entries() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("en", "", false)]),
+                transcript_xml(&[(0.0, 1.0, "rock &amp;amp;"), (1.0, 1.0, "roll")]),
+            ]
+        });
+        let config = TranscriptConfig::builder().base_url(base_url).build();
+
+        let text = YoutubeTranscript::fetch_transcript_text("dQw4w9WgXcQ", Some(config))
+            .await
+            .unwrap();
+        assert_eq!(text, "rock & roll");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_by_id() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("en", "", false)]),
+                transcript_xml(&[(0.0, 1.0, "hello")]),
+            ]
+        });
+        let config = TranscriptConfig::builder().base_url(base_url).build();
+        let video_id = VideoId::new("dQw4w9WgXcQ").unwrap();
+
+        let entries = YoutubeTranscript::fetch_transcript_by_id(&video_id, Some(config))
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_head_stops_after_n_entries() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("en", "", false)]),
+                transcript_xml(&[(0.0, 1.0, "one"), (1.0, 1.0, "two"), (2.0, 1.0, "three")]),
+            ]
+        });
+        let config = TranscriptConfig::builder().base_url(base_url).build();
+
+        let entries = YoutubeTranscript::fetch_transcript_head("dQw4w9WgXcQ", Some(config), 2)
+            .await
+            .unwrap();
+        let texts: Vec<_> = entries.iter().map(|entry| entry.text.as_str()).collect();
+        assert_eq!(texts, ["one", "two"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcripts_multilang_fetches_page_once() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("en", "", false), ("de", "", false)]),
+                transcript_xml(&[(0.0, 1.0, "hello")]),
+                transcript_xml(&[(0.0, 1.0, "hallo")]),
+            ]
+        });
+        let config = TranscriptConfig::builder()
+            .base_url(base_url)
+            .concurrency(1)
+            .build();
+
+        let results = YoutubeTranscript::fetch_transcripts_multilang(
+            "dQw4w9WgXcQ",
+            &["en", "de", "fr"],
+            Some(config),
+        )
+        .await
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results["en"].as_ref().unwrap()[0].text, "hello");
+        assert_eq!(results["de"].as_ref().unwrap()[0].text, "hallo");
+        assert_eq!(results["de"].as_ref().unwrap()[0].lang, "de");
+        assert!(matches!(
+            results["fr"],
+            Err(YoutubeTranscriptError::TranscriptNotAvailableLanguage(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_from_info_after_missing_language() {
+        let base_url = serve_responses(|base_url| {
+            vec![
+                captions_page(base_url, &[("de", "German", false)]),
+                transcript_xml(&[(0.0, 1.0, "hallo")]),
+            ]
+        });
+        let config = TranscriptConfig::builder()
+            .base_url(base_url)
+            .lang("en")
+            .build();
+
+        let error = YoutubeTranscript::fetch_transcript("dQw4w9WgXcQ", Some(config))
+            .await
+            .unwrap_err();
+        let fallback = &error.available_transcripts()[0];
+        assert_eq!(fallback.language_code, "de");
+
+        // Only the transcript is fetched; the server has no second video page to serve.
+        let entries = YoutubeTranscript::fetch_transcript_from_info(fallback, None)
+            .await
+            .unwrap();
+        assert_eq!(entries[0].text, "hallo");
+        assert_eq!(entries[0].language_name.as_deref(), Some("German"));
+    }
+
+    #[tokio::test]
+    async fn test_list_transcripts_from_mock_server() {
+        let base_url = serve_responses(|base_url| {
+            vec![captions_page(
+                base_url,
+                &[("en", "", false), ("de", "", true)],
+            )]
+        });
+        let config = TranscriptConfig::builder().base_url(base_url).build();
+
+        let tracks = YoutubeTranscript::list_transcripts("dQw4w9WgXcQ", Some(config))
+            .await
+            .unwrap();
+        let codes: Vec<_> = tracks.iter().map(|t| t.language_code.as_str()).collect();
+        assert_eq!(codes, ["en", "de"]);
+        assert!(tracks[1].is_generated);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_transcript_stream_with_invalid_url() {
+        let url = "https://www.example.com/watch?v=dQw4w9WgXcQ";
+        let result = YoutubeTranscript::fetch_transcript_stream(url, None).await;
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::InvalidVideoId)
+        ));
+    }
+
+    #[test]
+    fn test_parse_transcript_in_strict_mode() {
+        let parse = |body: &str, lang: &str, strict: bool| {
+            let transcript = TranscriptBody {
+                strict,
+                ..TranscriptBody::new(body, lang)
+            };
+            YoutubeTranscript::parse_transcript(transcript, "dQw4w9WgXcQ")
+        };
+        let malformed =
+            r#"<text start="abc" dur="1">bad</text><text start="1" dur="1">good</text>"#;
+        let valid = r#"<text start="1" dur="1">good</text>"#;
+
+        assert_eq!(parse(malformed, "en", false).unwrap().len(), 1);
+        assert!(parse(valid, "en", true).is_ok());
+        for (body, lang) in [(malformed, "en"), (valid, ""), ("", "en")] {
+            assert!(matches!(
+                parse(body, lang, true),
+                Err(YoutubeTranscriptError::TranscriptParseFailed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_transcript_fails_on_unknown_format() {
+        let transcript = TranscriptBody::new(
+            r#"<timedtext><body><p t="0" d="1500">a test</p></body></timedtext>"#,
+            "en",
+        );
+        let result = YoutubeTranscript::parse_transcript(transcript, "dQw4w9WgXcQ");
+        assert!(matches!(
+            result,
+            Err(YoutubeTranscriptError::TranscriptParseFailed(id)) if id == "dQw4w9WgXcQ"
+        ));
+
+        let transcript = TranscriptBody::new(String::new(), "en");
+        let entries = YoutubeTranscript::parse_transcript(transcript, "dQw4w9WgXcQ").unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_transcript_track_requests_xml_format() {
+        let caption_tracks = CaptionTracks::from(vec![serde_json::json!({
+            "languageCode": "en",
+            "name": { "simpleText": "English" },
+            "baseUrl": "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en&fmt=json3"
+        })]);
+        let track =
+            YoutubeTranscript::resolve_transcript_track(&caption_tracks, None, "dQw4w9WgXcQ")
+                .unwrap();
+        assert_eq!(
+            track.url,
+            "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en&fmt=srv1"
+        );
+        assert_eq!(track.language_name.as_deref(), Some("English"));
+        assert!(track.translated_from.is_none());
+
+        let config = TranscriptConfig::builder().translate_to("es").build();
+        let track = YoutubeTranscript::resolve_transcript_track(
+            &caption_tracks,
+            Some(&config),
+            "dQw4w9WgXcQ",
+        )
+        .unwrap();
+        assert_eq!(track.lang, "es");
+        assert_eq!(track.translated_from.as_deref(), Some("en"));
+
+        let config = TranscriptConfig::builder().format("json3").build();
+        let track = YoutubeTranscript::resolve_transcript_track(
+            &caption_tracks,
+            Some(&config),
+            "dQw4w9WgXcQ",
+        )
+        .unwrap();
+        assert!(track.url.ends_with("&fmt=json3"));
+    }
+
+    #[test]
+    fn test_resolve_transcript_track_reports_available_tracks() {
+        let caption_tracks = CaptionTracks::from(vec![serde_json::json!({
+            "languageCode": "de",
+            "name": { "simpleText": "German" },
+            "baseUrl": "https://example.com/de"
+        })]);
+        let config = TranscriptConfig::builder().lang("fr").build();
+        let result = YoutubeTranscript::resolve_transcript_track(
+            &caption_tracks,
+            Some(&config),
+            "dQw4w9WgXcQ",
+        );
+
+        let Err(error) = result else {
+            panic!("expected TranscriptNotAvailableLanguage");
+        };
+        assert_eq!(
+            error.to_string(),
+            "No transcripts are available in fr for this video (dQw4w9WgXcQ). Available languages: [\"de\"]"
+        );
+        let YoutubeTranscriptError::TranscriptNotAvailableLanguage(_, available, _) = error else {
+            panic!("expected TranscriptNotAvailableLanguage");
+        };
+        assert_eq!(available[0].language_code, "de");
+        assert_eq!(available[0].language_name.as_deref(), Some("German"));
+    }
+
+    #[test]
+    fn test_track_from_url() {
+        let url = "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en&kind=asr";
+        let track = YoutubeTranscript::track_from_url(url).unwrap();
+        assert_eq!(track.url, format!("{}&fmt=srv1", url));
+        assert_eq!(track.lang, "en");
+        assert!(track.is_generated);
+
+        let url = "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=de&fmt=json3";
+        let track = YoutubeTranscript::track_from_url(url).unwrap();
+        assert_eq!(track.url, url);
+        assert_eq!(track.lang, "de");
+        assert!(!track.is_generated);
+        assert!(track.translated_from.is_none());
+
+        let url = "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en&tlang=es";
+        let track = YoutubeTranscript::track_from_url(url).unwrap();
+        assert_eq!(track.lang, "es");
+        assert_eq!(track.translated_from.as_deref(), Some("en"));
+
+        assert!(matches!(
+            YoutubeTranscript::track_from_url("not a url"),
+            Err(YoutubeTranscriptError::TranscriptNotAvailable(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_query_param() {
+        let url = "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en";
+        assert_eq!(
+            with_query_param(url, "tlang", "es").unwrap(),
+            "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&lang=en&tlang=es"
+        );
+
+        let url = "https://www.youtube.com/api/timedtext?tlang=de&v=dQw4w9WgXcQ";
+        assert_eq!(
+            with_query_param(url, "tlang", "es").unwrap(),
+            "https://www.youtube.com/api/timedtext?v=dQw4w9WgXcQ&tlang=es"
+        );
+        assert!(with_query_param("not a url", "tlang", "es").is_none());
     }
 }
@@ -0,0 +1,443 @@
+use crate::types::*;
+
+/// Formats a number of seconds as an `HH:MM:SS<sep>mmm` timestamp.
+///
+/// # Arguments
+///
+/// * `seconds` - The time in seconds. Negative values are treated as zero.
+/// * `separator` - The character placed between seconds and milliseconds.
+///
+/// # Returns
+///
+/// * `String` - The formatted timestamp.
+pub(crate) fn format_timestamp(seconds: f64, separator: char) -> String {
+    let total_millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    let hours = total_millis / 3_600_000;
+    let minutes = (total_millis / 60_000) % 60;
+    let secs = (total_millis / 1000) % 60;
+    let millis = total_millis % 1000;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        hours, minutes, secs, separator, millis
+    )
+}
+
+/// Converts transcript entries into a SubRip (SRT) subtitle document.
+///
+/// Blank lines are removed from cue text, since they would end the cue early, and entries
+/// left without text are skipped. Cues are numbered consecutively.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `String` - The transcript in SRT format.
+pub fn to_srt(entries: &[TranscriptResponse]) -> String {
+    let mut srt = String::new();
+    let mut number = 0;
+    for entry in entries {
+        let text = remove_blank_lines(&entry.text);
+        if text.is_empty() {
+            continue;
+        }
+        number += 1;
+        srt.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            number,
+            format_timestamp(entry.offset, ','),
+            format_timestamp(entry.offset + entry.duration, ','),
+            text
+        ));
+    }
+    srt
+}
+
+/// Converts transcript entries into a WebVTT subtitle document.
+///
+/// Cues whose duration is zero or negative are given a minimal one-millisecond duration,
+/// and cue text is escaped so it cannot terminate the cue or be read as markup. Entries
+/// without text are skipped, since an empty cue would end at its timing line.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `String` - The transcript in WebVTT format.
+pub fn to_vtt(entries: &[TranscriptResponse]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    for entry in entries {
+        let text = escape_vtt_text(&entry.text);
+        if text.is_empty() {
+            continue;
+        }
+        let start = entry.offset.max(0.0);
+        let end = (entry.offset + entry.duration).max(start + 0.001);
+        vtt.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(start, '.'),
+            format_timestamp(end, '.'),
+            text
+        ));
+    }
+    vtt
+}
+
+/// Converts transcript entries into a Markdown list with one `HH:MM:SS` timestamped item per entry.
+///
+/// When `link_prefix` is given, each timestamp links to the prefix followed by the entry's
+/// start in whole seconds, e.g. `- [00:01:23](https://youtu.be/ID?t=83) text` for a prefix of
+/// `https://youtu.be/ID?t=`.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+/// * `link_prefix` - An optional URL prefix used to turn timestamps into links.
+///
+/// # Returns
+///
+/// * `String` - The transcript as a Markdown list.
+pub fn to_markdown(entries: &[TranscriptResponse], link_prefix: Option<&str>) -> String {
+    let mut markdown = String::new();
+    for entry in entries {
+        let seconds = entry.offset.max(0.0) as u64;
+        let timestamp = format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            (seconds / 60) % 60,
+            seconds % 60
+        );
+        let timestamp = match link_prefix {
+            Some(prefix) => format!("[{}]({}{})", timestamp, prefix, seconds),
+            None => timestamp,
+        };
+        markdown.push_str(&format!("- {} {}\n", timestamp, single_line(&entry.text)));
+    }
+    markdown
+}
+
+/// Concatenates the text of all transcript entries into a single line.
+///
+/// Entries are joined with spaces, and any newlines left inside an entry are collapsed into single spaces.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `String` - The full transcript text.
+pub fn to_plain_text(entries: &[TranscriptResponse]) -> String {
+    entries
+        .iter()
+        .map(|entry| single_line(&entry.text))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Concatenates the text of all transcript entries, one entry per line.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `String` - The transcript text with each entry on its own line.
+pub fn to_text_lines(entries: &[TranscriptResponse]) -> String {
+    entries
+        .iter()
+        .map(|entry| single_line(&entry.text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Joins transcript entries into paragraphs, separated wherever the captions pause.
+///
+/// Consecutive entries are joined with spaces. A new paragraph starts when the gap between the
+/// end of the preceding entries and the start of the next entry exceeds `gap_secs`; overlapping
+/// entries never start a new paragraph. Entries without text are skipped.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries, sorted by offset.
+/// * `gap_secs` - The longest pause in seconds that is kept within a paragraph.
+///
+/// # Returns
+///
+/// * `Vec<String>` - The paragraphs in order.
+pub fn to_paragraphs(entries: &[TranscriptResponse], gap_secs: f64) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut end = f64::NEG_INFINITY;
+    for entry in entries {
+        let text = single_line(&entry.text);
+        if text.is_empty() {
+            continue;
+        }
+        if !current.is_empty() && entry.offset - end > gap_secs {
+            paragraphs.push(current.join(" "));
+            current.clear();
+        }
+        current.push(text);
+        // Measure the next gap from the latest end so far, so a short entry inside a longer
+        // one does not open a false gap.
+        end = end.max(entry.offset + entry.duration);
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+    paragraphs
+}
+
+/// Converts transcript entries into a CSV document with the columns `offset,duration,end,text,lang`.
+///
+/// The document starts with a header row, and fields are quoted and escaped per RFC 4180.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `String` - The transcript in CSV format.
+pub fn to_csv(entries: &[TranscriptResponse]) -> String {
+    let mut csv = String::from("offset,duration,end,text,lang\r\n");
+    for entry in entries {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\r\n",
+            entry.offset,
+            entry.duration,
+            entry.offset + entry.duration,
+            escape_csv_field(&entry.text),
+            escape_csv_field(&entry.lang)
+        ));
+    }
+    csv
+}
+
+/// Converts transcript entries into a JSON array.
+///
+/// Each entry is serialized with its fields in declaration order, so the output is stable
+/// across runs and suitable for snapshot tests.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+/// * `pretty` - Whether to indent the output for readability.
+///
+/// # Returns
+///
+/// * `String` - The transcript as a JSON array.
+pub fn to_json(entries: &[TranscriptResponse], pretty: bool) -> String {
+    let json = if pretty {
+        serde_json::to_string_pretty(entries)
+    } else {
+        serde_json::to_string(entries)
+    };
+    // Serializing plain strings, numbers, and booleans cannot fail.
+    json.expect("transcript entries are always serializable")
+}
+
+/// Collapses the lines of a text into a single line separated by spaces.
+fn single_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Removes the blank lines from cue text, which would end the cue early in SRT and WebVTT.
+fn remove_blank_lines(text: &str) -> String {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escapes cue text for WebVTT, removing blank lines that would end the cue early.
+fn escape_vtt_text(text: &str) -> String {
+    remove_blank_lines(text)
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Quotes a CSV field if it contains a comma, quote, or line break, doubling any quotes.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, offset: f64, duration: f64) -> TranscriptResponse {
+        TranscriptResponse {
+            text: text.to_string(),
+            duration,
+            offset,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(1.05, ','), "00:00:01,050");
+        assert_eq!(format_timestamp(3725.5, ','), "01:02:05,500");
+    }
+
+    #[test]
+    fn test_to_srt() {
+        let entries = vec![entry("hello", 0.5, 1.5), entry("world", 3600.0, 2.25)];
+        assert_eq!(
+            to_srt(&entries),
+            "1\n00:00:00,500 --> 00:00:02,000\nhello\n\n\
+             2\n01:00:00,000 --> 01:00:02,250\nworld\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_srt_skips_empty_cues() {
+        let entries = vec![
+            entry("", 0.0, 1.0),
+            entry("hello", 1.0, 1.0),
+            entry(" \n", 2.0, 1.0),
+            entry("world", 3.0, 1.0),
+        ];
+        assert_eq!(
+            to_srt(&entries),
+            "1\n00:00:01,000 --> 00:00:02,000\nhello\n\n\
+             2\n00:00:03,000 --> 00:00:04,000\nworld\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_srt_removes_blank_lines() {
+        let entries = vec![entry("first\n\n  \nsecond", 2.0, 1.0)];
+        assert_eq!(
+            to_srt(&entries),
+            "1\n00:00:02,000 --> 00:00:03,000\nfirst\nsecond\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_plain_text() {
+        let entries = vec![entry("hello\nthere", 0.0, 1.0), entry("world", 1.0, 1.0)];
+        assert_eq!(to_plain_text(&entries), "hello there world");
+    }
+
+    #[test]
+    fn test_to_paragraphs() {
+        let entries = vec![
+            entry("hello", 0.0, 1.0),
+            entry("there", 1.2, 3.0),
+            entry("again", 2.0, 1.0),
+            entry("", 4.0, 1.0),
+            entry("new\nparagraph", 6.0, 1.0),
+            entry("end", 9.0, 1.0),
+        ];
+        assert_eq!(
+            to_paragraphs(&entries, 1.5),
+            vec!["hello there again", "new paragraph", "end"]
+        );
+        assert_eq!(to_paragraphs(&entries, 5.0).len(), 1);
+        assert!(to_paragraphs(&[], 1.5).is_empty());
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let entries = vec![
+            entry("hello\nthere", 83.45, 1.0),
+            entry("world", 3725.0, 1.0),
+        ];
+        assert_eq!(
+            to_markdown(&entries, Some("https://youtu.be/dQw4w9WgXcQ?t=")),
+            "- [00:01:23](https://youtu.be/dQw4w9WgXcQ?t=83) hello there\n\
+             - [01:02:05](https://youtu.be/dQw4w9WgXcQ?t=3725) world\n"
+        );
+        assert_eq!(
+            to_markdown(&entries, None),
+            "- 00:01:23 hello there\n- 01:02:05 world\n"
+        );
+    }
+
+    #[test]
+    fn test_to_text_lines() {
+        let entries = vec![entry("hello\nthere", 0.0, 1.0), entry("world", 1.0, 1.0)];
+        assert_eq!(to_text_lines(&entries), "hello there\nworld");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let entries = vec![
+            entry("hello", 0.5, 1.5),
+            entry("say \"hi\", then\nleave", 2.0, 3.0),
+        ];
+        assert_eq!(
+            to_csv(&entries),
+            "offset,duration,end,text,lang\r\n\
+             0.5,1.5,2,hello,en\r\n\
+             2,3,5,\"say \"\"hi\"\", then\nleave\",en\r\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let entries = vec![entry("hello", 0.5, 1.5)];
+        assert_eq!(
+            to_json(&entries, false),
+            r#"[{"text":"hello","duration":1.5,"offset":0.5,"lang":"en","is_generated":false,"index":0}]"#
+        );
+        assert!(to_json(&entries, true).starts_with("[\n  {\n    \"text\": \"hello\","));
+        assert_eq!(to_json(&[], false), "[]");
+    }
+
+    #[test]
+    fn test_to_vtt() {
+        let entries = vec![entry("hello", 0.5, 1.5), entry("a -> b & c", 3600.0, 2.25)];
+        assert_eq!(
+            to_vtt(&entries),
+            "WEBVTT\n\n\
+             00:00:00.500 --> 00:00:02.000\nhello\n\n\
+             01:00:00.000 --> 01:00:02.250\na -&gt; b &amp; c\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_vtt_zero_duration_and_blank_lines() {
+        let entries = vec![entry("first\n\nsecond", 2.0, 0.0)];
+        assert_eq!(
+            to_vtt(&entries),
+            "WEBVTT\n\n00:00:02.000 --> 00:00:02.001\nfirst\nsecond\n\n"
+        );
+    }
+
+    #[test]
+    fn test_to_vtt_skips_empty_cues() {
+        let entries = vec![
+            entry("", 0.0, 1.0),
+            entry("hello", 1.0, 1.0),
+            entry(" \n", 2.0, 1.0),
+        ];
+        assert_eq!(
+            to_vtt(&entries),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nhello\n\n"
+        );
+        assert_eq!(to_vtt(&[entry("", 0.0, 1.0)]), "WEBVTT\n\n");
+    }
+}
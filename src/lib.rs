@@ -1,17 +1,41 @@
+/// The `blocking` module provides a synchronous API for fetching YouTube transcripts.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// The `cache` module provides an in-memory cache of fetched transcripts.
+#[cfg(feature = "cache")]
+pub mod cache;
+
 /// The `errors` module defines the error types for the `ytranscript` crate.
 pub mod errors;
 
 /// The `fetch` module provides the functionality to fetch YouTube transcripts.
+#[cfg(feature = "fetch")]
 pub mod fetch;
 
+/// The `format` module provides functions to export transcripts into subtitle and text formats.
+pub mod format;
+
+/// The `parse` module provides the transcript parsers, which need no networking.
+pub mod parse;
+
 /// The `regex` module defines the regular expression patterns used in the `ytranscript` crate.
 pub mod regex;
 
 /// The `types` module defines the data structures used in the `ytranscript` crate.
 pub mod types;
 
+/// The `utils` module provides helpers for analyzing and reshaping transcript entries.
+pub mod utils;
+
 // Re-export the modules for easier access
+#[cfg(feature = "cache")]
+pub use crate::cache::*;
 pub use crate::errors::*;
+#[cfg(feature = "fetch")]
 pub use crate::fetch::*;
+pub use crate::format::*;
+pub use crate::parse::*;
 pub use crate::regex::*;
 pub use crate::types::*;
+pub use crate::utils::*;
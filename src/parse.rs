@@ -0,0 +1,676 @@
+use crate::regex::*;
+use crate::types::*;
+
+/// The raw transcript body along with details of the caption track it was fetched from.
+///
+/// The fetch-only details are unused when parsing a body obtained by other means.
+#[cfg_attr(not(feature = "fetch"), allow(dead_code))]
+pub(crate) struct TranscriptBody {
+    /// The transcript body as served by YouTube.
+    pub(crate) body: String,
+    /// The URL from which the transcript body was fetched.
+    pub(crate) url: String,
+    /// The language code of the transcript.
+    pub(crate) lang: String,
+    /// The human-readable language name of the transcript, if known.
+    pub(crate) language_name: Option<String>,
+    /// Whether the caption track was automatically generated by YouTube.
+    pub(crate) is_generated: bool,
+    /// The language code of the caption track, if the transcript is a machine translation of it.
+    pub(crate) translated_from: Option<String>,
+    /// Whether JSON3 segments are parsed into separate word-level entries.
+    pub(crate) word_level: bool,
+    /// Whether parse anomalies fail parsing instead of being worked around.
+    pub(crate) strict: bool,
+    /// Whether line breaks within a cue are kept in the entry text.
+    pub(crate) preserve_newlines: bool,
+    /// Whether inline markup tags in cue text are kept.
+    pub(crate) keep_markup: bool,
+}
+
+impl TranscriptBody {
+    /// Creates a transcript body in a given language, with no track details and the default
+    /// parsing options.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The transcript body in the `srv1` XML or `json3` format.
+    /// * `lang` - The language code of the transcript.
+    ///
+    /// # Returns
+    ///
+    /// * `TranscriptBody` - The transcript body, ready to be customized with struct update syntax.
+    pub(crate) fn new(body: impl Into<String>, lang: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            url: String::new(),
+            lang: lang.into(),
+            language_name: None,
+            is_generated: false,
+            translated_from: None,
+            word_level: false,
+            strict: false,
+            preserve_newlines: false,
+            keep_markup: false,
+        }
+    }
+}
+
+/// An iterator that lazily parses the entries of a transcript.
+///
+/// Both the `srv1` XML format and the `json3` format are recognized.
+pub struct TranscriptIter {
+    /// The raw transcript body.
+    body: String,
+    /// The byte position in `body` where the next search starts.
+    position: usize,
+    /// The language code reported on each entry.
+    lang: String,
+    /// The human-readable language name reported on each entry, if known.
+    language_name: Option<String>,
+    /// Whether the transcript comes from an auto-generated caption track.
+    is_generated: bool,
+    /// The entries parsed up front when the body is in the JSON3 format.
+    json_entries: Option<std::vec::IntoIter<TranscriptResponse>>,
+    /// The index assigned to the next parsed entry.
+    index: usize,
+    /// The number of entries skipped so far because their timestamps are malformed.
+    pub(crate) skipped: usize,
+    /// Whether line breaks within a cue are kept in the entry text.
+    preserve_newlines: bool,
+    /// Whether inline markup tags in cue text are kept.
+    keep_markup: bool,
+}
+
+impl TranscriptIter {
+    /// Creates an iterator over the entries of a fetched transcript body.
+    pub(crate) fn new(transcript: TranscriptBody) -> Self {
+        let json3 = parse_json3_transcript(&transcript);
+        let mut entries = Self::xml(transcript);
+        if let Some((json_entries, skipped)) = json3 {
+            entries.json_entries = Some(json_entries.into_iter());
+            entries.skipped = skipped;
+        }
+        entries
+    }
+
+    /// Creates an iterator that only parses a transcript body as `srv1` XML, without trying JSON3.
+    fn xml(transcript: TranscriptBody) -> Self {
+        Self {
+            body: transcript.body,
+            position: 0,
+            lang: transcript.lang,
+            language_name: transcript.language_name,
+            is_generated: transcript.is_generated,
+            json_entries: None,
+            index: 0,
+            skipped: 0,
+            preserve_newlines: transcript.preserve_newlines,
+            keep_markup: transcript.keep_markup,
+        }
+    }
+
+    /// Creates an iterator over the entries of a transcript body obtained by other means, such as
+    /// a fixture file or a custom HTTP client.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The transcript body in the `srv1` XML or `json3` format.
+    /// * `lang` - The language code reported on each entry.
+    ///
+    /// # Returns
+    ///
+    /// * `TranscriptIter` - An iterator over the `TranscriptResponse` entries of the body.
+    pub fn from_body(body: impl Into<String>, lang: impl Into<String>) -> Self {
+        Self::new(TranscriptBody::new(body, lang))
+    }
+}
+
+impl Iterator for TranscriptIter {
+    type Item = TranscriptResponse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entries) = &mut self.json_entries {
+            return entries.next();
+        }
+
+        loop {
+            let cap = XML_TRANSCRIPT_REGEX.captures_at(&self.body, self.position)?;
+            self.position = cap.get(0)?.end();
+
+            // Attributes are looked up by name, so their order and any extra attributes
+            // (e.g. `w` or `append`) do not matter. A missing duration means zero.
+            let attributes = &cap[1];
+            let offset = xml_attribute(attributes, "start").and_then(parse_seconds);
+            let duration = match xml_attribute(attributes, "dur") {
+                Some(value) => parse_seconds(value),
+                None => Some(0.0),
+            };
+
+            // Entries with malformed timestamps are skipped rather than moved to the start of the video.
+            let (Some(offset), Some(duration)) = (offset, duration) else {
+                self.skipped += 1;
+                continue;
+            };
+            let text = cap.get(2).map_or("", |text| text.as_str());
+            // Transcript XML escapes cue text twice (e.g. `&amp;#39;`), so both layers are decoded.
+            // Markup is stripped between the XML and the HTML layer, so that tags escaped once are
+            // removed while literal text escaped twice, such as `&amp;lt;div&amp;gt;`, is kept.
+            let mut text = decode_html_entities(text);
+            if !self.keep_markup {
+                text = strip_markup(&text);
+            }
+            let text = decode_html_entities(&text);
+
+            let index = self.index;
+            self.index += 1;
+            return Some(TranscriptResponse {
+                text: clean_text(&text, self.preserve_newlines),
+                duration,
+                offset,
+                lang: self.lang.clone(),
+                language_name: self.language_name.clone(),
+                is_generated: self.is_generated,
+                index,
+            });
+        }
+    }
+}
+
+/// Parses a transcript body in YouTube's `srv1` XML format into its entries.
+///
+/// This is the parser used when fetching transcripts, exposed for bodies obtained by other means,
+/// such as fixture files. Cue text is decoded and its whitespace collapsed, and entries with
+/// malformed timestamps are skipped.
+///
+/// # Arguments
+///
+/// * `body` - The transcript XML, e.g. `<transcript><text start="0" dur="1">hi</text></transcript>`.
+/// * `lang` - The language code reported on each entry.
+///
+/// # Returns
+///
+/// * `Vec<TranscriptResponse>` - The parsed entries, or an empty vector if the body holds none.
+pub fn parse_transcript_xml(body: &str, lang: &str) -> Vec<TranscriptResponse> {
+    // Only the XML format is parsed, even if the body happens to be JSON3.
+    TranscriptIter::xml(TranscriptBody::new(body, lang)).collect()
+}
+
+/// Looks up the value of an attribute among the attributes of an XML element.
+///
+/// # Arguments
+///
+/// * `attributes` - The attributes of the element, e.g. ` start="1.5" dur="2"`.
+/// * `name` - The name of the attribute.
+///
+/// # Returns
+///
+/// * `Option<&str>` - The raw value of the attribute, or `None` if the element does not have it.
+fn xml_attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    XML_ATTRIBUTE_REGEX
+        .captures_iter(attributes)
+        .find(|cap| &cap[1] == name)
+        .and_then(|cap| cap.get(2).or_else(|| cap.get(3)))
+        .map(|value| value.as_str())
+}
+
+/// Removes inline markup tags such as `<i>` or `<font color="#E5E5E5">` from a cue's text.
+///
+/// A `<br>` becomes a line break, so it is kept with `preserve_newlines`. Text that only resembles
+/// a tag, e.g. `a < b`, is left untouched.
+///
+/// # Arguments
+///
+/// * `text` - The text of the cue, with its XML escaping decoded.
+///
+/// # Returns
+///
+/// * `String` - The text without markup tags.
+fn strip_markup(text: &str) -> String {
+    MARKUP_TAG_REGEX
+        .replace_all(text, |cap: &::regex::Captures| {
+            if cap[1].eq_ignore_ascii_case("br") {
+                "\n"
+            } else {
+                ""
+            }
+        })
+        .into_owned()
+}
+
+/// Trims a cue's text and collapses each run of whitespace, including newlines, into one space.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cleans up the whitespace in a cue's text.
+///
+/// # Arguments
+///
+/// * `text` - The decoded text of the cue.
+/// * `preserve_newlines` - Whether to keep line breaks, collapsing whitespace within each line
+///   and dropping blank lines, instead of collapsing all whitespace into single spaces.
+///
+/// # Returns
+///
+/// * `String` - The cleaned-up text.
+fn clean_text(text: &str, preserve_newlines: bool) -> String {
+    if !preserve_newlines {
+        return collapse_whitespace(text);
+    }
+    text.lines()
+        .map(collapse_whitespace)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses a timestamp attribute of the transcript XML, given in seconds.
+///
+/// # Arguments
+///
+/// * `value` - The attribute value, e.g. `1.234`.
+///
+/// # Returns
+///
+/// * `Option<f64>` - The number of seconds, or `None` if the value is not a finite number.
+fn parse_seconds(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| seconds.is_finite())
+}
+
+/// Parses a transcript body in YouTube's JSON3 format (`fmt=json3`).
+///
+/// Each event becomes one entry with the text of all its segments, or, when `word_level` is set
+/// on the transcript, one entry per segment timed from its `tOffsetMs` to the start of the next
+/// segment (or the end of the event).
+///
+/// # Arguments
+///
+/// * `transcript` - The fetched transcript body.
+///
+/// # Returns
+///
+/// * `Option<(Vec<TranscriptResponse>, usize)>` - The parsed entries and the number of events
+///   skipped for lacking a start time, or `None` if the body is not JSON3.
+fn parse_json3_transcript(transcript: &TranscriptBody) -> Option<(Vec<TranscriptResponse>, usize)> {
+    if !transcript.body.trim_start().starts_with('{') {
+        return None;
+    }
+    let json: serde_json::Value = serde_json::from_str(&transcript.body).ok()?;
+    let events = json.get("events")?.as_array()?;
+
+    let entry = |text: String, offset: f64, duration: f64| TranscriptResponse {
+        text,
+        duration: duration / 1000.0,
+        offset: offset / 1000.0,
+        lang: transcript.lang.clone(),
+        language_name: transcript.language_name.clone(),
+        is_generated: transcript.is_generated,
+        index: 0,
+    };
+
+    let mut entries = Vec::new();
+    let mut skipped = 0;
+    for event in events {
+        // Events without segments only carry window styling, not cue text.
+        let Some(segs) = event.get("segs").and_then(|segs| segs.as_array()) else {
+            continue;
+        };
+        // Events without a start time are skipped; a missing duration means zero.
+        let Some(start) = event.get("tStartMs").and_then(|v| v.as_f64()) else {
+            skipped += 1;
+            continue;
+        };
+        let duration = event
+            .get("dDurationMs")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+
+        if !transcript.word_level {
+            let text: String = segs
+                .iter()
+                .filter_map(|seg| seg.get("utf8").and_then(|text| text.as_str()))
+                .collect();
+            if !text.trim().is_empty() {
+                entries.push(entry(
+                    clean_text(&text, transcript.preserve_newlines),
+                    start,
+                    duration,
+                ));
+            }
+            continue;
+        }
+
+        let words: Vec<(&str, f64)> = segs
+            .iter()
+            .filter_map(|seg| {
+                let text = seg.get("utf8")?.as_str()?.trim();
+                let offset = seg.get("tOffsetMs").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                (!text.is_empty()).then_some((text, start + offset))
+            })
+            .collect();
+        for (i, &(text, offset)) in words.iter().enumerate() {
+            let end = words.get(i + 1).map_or(start + duration, |&(_, next)| next);
+            entries.push(entry(text.to_string(), offset, (end - offset).max(0.0)));
+        }
+    }
+
+    let entries = entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| TranscriptResponse { index, ..entry })
+        .collect();
+    Some((entries, skipped))
+}
+
+/// Decodes HTML/XML character references in transcript text.
+///
+/// Handles the common named entities as well as decimal (`&#39;`) and hexadecimal (`&#x27;`)
+/// numeric references. Unknown or malformed references are left untouched.
+///
+/// # Arguments
+///
+/// * `text` - A string slice containing the raw text.
+///
+/// # Returns
+///
+/// * `String` - The text with all recognized entities replaced by their characters.
+pub(crate) fn decode_html_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Resolves a single entity name (without the surrounding `&` and `;`) to its character.
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(numeric) = entity.strip_prefix('#') {
+        let code = match numeric.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => numeric.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transcript_iter() {
+        let transcript = TranscriptBody {
+            is_generated: true,
+            ..TranscriptBody::new(
+                r#"<transcript><text start="0.5" dur="1.5">it&amp;#39;s</text><text start="2" dur="3">a test</text></transcript>"#,
+                "en",
+            )
+        };
+        let mut entries = TranscriptIter::new(transcript);
+
+        let first = entries.next().unwrap();
+        assert_eq!(first.text, "it's");
+        assert_eq!(first.offset, 0.5);
+        assert_eq!(first.duration, 1.5);
+        assert_eq!(first.lang, "en");
+        assert!(first.is_generated);
+
+        let second = entries.next().unwrap();
+        assert_eq!(second.text, "a test");
+        assert_eq!(second.offset, 2.0);
+        assert_eq!((first.index, second.index), (0, 1));
+        assert!(entries.next().is_none());
+    }
+
+    #[test]
+    fn test_transcript_iter_from_body() {
+        let body = r#"<transcript><text start="1" dur="2">hello &amp;amp; bye</text></transcript>"#;
+        let entries: Vec<_> = TranscriptIter::from_body(body, "en").collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, "hello & bye");
+        assert_eq!(entries[0].offset, 1.0);
+        assert_eq!(entries[0].lang, "en");
+        assert!(!entries[0].is_generated);
+    }
+
+    #[test]
+    fn test_parse_transcript_xml() {
+        let body = r#"<?xml version="1.0" encoding="utf-8" ?><transcript>
+<text start="0.5" dur="1.5">don&amp;#39;t
+stop</text>
+<text start="bad" dur="1">skipped</text>
+<text start="2" dur="3">me now</text>
+</transcript>"#;
+        let entries = parse_transcript_xml(body, "en");
+        let texts: Vec<_> = entries.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["don't stop", "me now"]);
+        assert_eq!(entries[1].index, 1);
+        assert_eq!(entries[1].lang, "en");
+
+        let json3 = r#"{"events":[{"tStartMs":0,"dDurationMs":1000,"segs":[{"utf8":"hi"}]}]}"#;
+        assert!(parse_transcript_xml(json3, "en").is_empty());
+        assert!(parse_transcript_xml("", "en").is_empty());
+    }
+
+    #[test]
+    fn test_parse_transcript_xml_with_reordered_attributes() {
+        let body = r#"<transcript>
+<text dur="1.5" start="0.5">reordered</text>
+<text start='2' w="1" dur='3' append="1">extra</text>
+<text start="5"/>
+<text start="6">no duration</text>
+<text dur="1">no start</text>
+<textual start="7" dur="1">ignored</textual>
+</transcript>"#;
+        let entries: Vec<_> = parse_transcript_xml(body, "en")
+            .into_iter()
+            .map(|e| (e.text, e.offset, e.duration))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("reordered".to_string(), 0.5, 1.5),
+                ("extra".to_string(), 2.0, 3.0),
+                (String::new(), 5.0, 0.0),
+                ("no duration".to_string(), 6.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_attribute() {
+        let attributes = r#" start="1.5" dur='2' data-start="9""#;
+        assert_eq!(xml_attribute(attributes, "start"), Some("1.5"));
+        assert_eq!(xml_attribute(attributes, "dur"), Some("2"));
+        assert_eq!(xml_attribute(attributes, "w"), None);
+    }
+
+    #[test]
+    fn test_transcript_iter_collapses_whitespace() {
+        let transcript = TranscriptBody::new(
+            "<text start=\"0\" dur=\"1\">\n  hello   world \n</text>",
+            "en",
+        );
+        let entries: Vec<_> = TranscriptIter::new(transcript).collect();
+        assert_eq!(entries[0].text, "hello world");
+    }
+
+    #[test]
+    fn test_transcript_iter_skips_malformed_timestamps() {
+        let transcript = TranscriptBody::new(
+            r#"<text start="abc" dur="1">bad start</text><text start="1.234" dur="">bad dur</text><text start="2.345" dur="0.5">good</text>"#,
+            "en",
+        );
+        let entries: Vec<_> = TranscriptIter::new(transcript).collect();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, "good");
+        assert_eq!(entries[0].index, 0);
+        assert_eq!(entries[0].offset, 2.345);
+        assert_eq!(entries[0].duration, 0.5);
+    }
+
+    #[test]
+    fn test_transcript_iter_parses_json3() {
+        let transcript = TranscriptBody::new(
+            r#"{"events":[{"tStartMs":0,"dDurationMs":5000,"id":1},{"tStartMs":500,"dDurationMs":1500,"segs":[{"utf8":"it's "},{"utf8":"a test"}]},{"tStartMs":2000,"segs":[{"utf8":"\n"}]}]}"#,
+            "en",
+        );
+        let entries: Vec<_> = TranscriptIter::new(transcript).collect();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, "it's a test");
+        assert_eq!(entries[0].offset, 0.5);
+        assert_eq!(entries[0].duration, 1.5);
+        assert_eq!(entries[0].lang, "en");
+    }
+
+    #[test]
+    fn test_transcript_iter_parses_json3_word_level() {
+        let transcript = TranscriptBody {
+            is_generated: true,
+            word_level: true,
+            ..TranscriptBody::new(
+                r#"{"events":[{"tStartMs":1000,"dDurationMs":2000,"segs":[{"utf8":"hello"},{"utf8":" big","tOffsetMs":400},{"utf8":" world","tOffsetMs":1200}]}]}"#,
+                "en",
+            )
+        };
+        let entries: Vec<_> = TranscriptIter::new(transcript).collect();
+
+        let words: Vec<_> = entries
+            .iter()
+            .map(|e| (e.text.as_str(), e.offset, e.duration, e.index))
+            .collect();
+        assert_eq!(
+            words,
+            [
+                ("hello", 1.0, 0.4, 0),
+                ("big", 1.4, 0.8, 1),
+                ("world", 2.2, 0.8, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transcript_iter_preserves_newlines() {
+        let parse = |body: &str, preserve_newlines: bool| {
+            let transcript = TranscriptBody {
+                preserve_newlines,
+                ..TranscriptBody::new(body, "en")
+            };
+            TranscriptIter::new(transcript)
+                .map(|entry| entry.text)
+                .collect::<Vec<_>>()
+        };
+
+        let xml = "<transcript><text start=\"0\" dur=\"1\">  roses  are\n\nred\r\n violets </text></transcript>";
+        assert_eq!(parse(xml, false), ["roses are red violets"]);
+        assert_eq!(parse(xml, true), ["roses are\nred\nviolets"]);
+
+        let json3 =
+            r#"{"events":[{"tStartMs":0,"dDurationMs":1000,"segs":[{"utf8":"roses are\nred"}]}]}"#;
+        assert_eq!(parse(json3, false), ["roses are red"]);
+        assert_eq!(parse(json3, true), ["roses are\nred"]);
+    }
+
+    #[test]
+    fn test_transcript_iter_strips_markup() {
+        let parse = |body: &str, keep_markup: bool| {
+            let transcript = TranscriptBody {
+                keep_markup,
+                ..TranscriptBody::new(body, "en")
+            };
+            TranscriptIter::new(transcript)
+                .map(|entry| entry.text)
+                .collect::<Vec<_>>()
+        };
+
+        let xml = concat!(
+            "<transcript>",
+            "<text start=\"0\" dur=\"1\">hello <i>there <b>big</b></i> world</text>",
+            "<text start=\"1\" dur=\"1\">&lt;font color=&quot;#E5E5E5&quot;&gt;grey&lt;/font&gt;</text>",
+            "<text start=\"2\" dur=\"1\">one<br/>two</text>",
+            "<text start=\"3\" dur=\"1\">a &lt; b</text>",
+            "<text start=\"4\" dur=\"1\">use the &amp;lt;div&amp;gt; tag</text>",
+            "<text start=\"5\" dur=\"1\">AT&amp;amp;T says &amp;lt;hi&amp;gt;</text>",
+            "</transcript>"
+        );
+        assert_eq!(
+            parse(xml, false),
+            [
+                "hello there big world",
+                "grey",
+                "one two",
+                "a < b",
+                "use the <div> tag",
+                "AT&T says <hi>"
+            ]
+        );
+        assert_eq!(
+            parse(xml, true),
+            [
+                "hello <i>there <b>big</b></i> world",
+                "<font color=\"#E5E5E5\">grey</font>",
+                "one<br/>two",
+                "a < b",
+                "use the <div> tag",
+                "AT&T says <hi>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_html_entities() {
+        assert_eq!(decode_html_entities("it&#39;s a test"), "it's a test");
+        assert_eq!(
+            decode_html_entities("&quot;a&quot; &amp; &lt;b&gt;"),
+            "\"a\" & <b>"
+        );
+        assert_eq!(decode_html_entities("caf&#xE9; &#x27;"), "café '");
+    }
+
+    #[test]
+    fn test_decode_html_entities_leaves_literal_text() {
+        assert_eq!(decode_html_entities("plain text"), "plain text");
+        assert_eq!(
+            decode_html_entities("fish & chips; &unknown;"),
+            "fish & chips; &unknown;"
+        );
+    }
+}
@@ -1,9 +1,32 @@
+use ::regex::Regex;
+use once_cell::sync::Lazy;
+
 /// Regular expression pattern for extracting YouTube video IDs from URLs.
-pub const RE_YOUTUBE: &str =
-    r#"(?:youtube\.com\/(?:[^\/]+\/.+\/|(?:v|e(?:mbed)?)\/|.*[?&]v=)|youtu\.be\/)([^"&?\/\s]{11})"#;
+pub const RE_YOUTUBE: &str = r#"(?:youtube(?:-nocookie)?\.com\/(?:[^\/]+\/.+\/|(?:v|e(?:mbed)?|shorts|live)\/|.*[?&]v=)|youtu\.be\/)([^"&?\/\s]{11})"#;
 
 /// User-Agent string to be used for HTTP requests to YouTube.
 pub const USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_4) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/85.0.4183.83 Safari/537.36,gzip(gfe)";
 
-/// Regular expression pattern for extracting text, start time, and duration from YouTube transcript XML.
-pub const RE_XML_TRANSCRIPT: &str = r#"<text start="([^"]*)" dur="([^"]*)">([^<]*)<\/text>"#;
+/// Regular expression pattern for extracting the attributes and text of each `<text>` element
+/// in YouTube transcript XML. The text runs up to the closing `</text>`, including any nested
+/// markup tags. Self-closing elements match with no text.
+pub const RE_XML_TRANSCRIPT: &str = r#"<text\b([^>]*?)(?:/>|>((?s:.*?))<\/text>)"#;
+
+/// Regular expression pattern for matching inline markup tags in cue text, e.g. `<i>`, `</b>`, or
+/// `<font color="#E5E5E5">`. The tag name is captured.
+pub const RE_MARKUP_TAG: &str = r#"</?([A-Za-z][A-Za-z0-9]*)(?:\s[^<>]*)?/?>"#;
+
+/// Regular expression pattern for extracting the name and value of each attribute of an XML element.
+pub const RE_XML_ATTRIBUTE: &str = r#"([A-Za-z_][\w.:-]*)\s*=\s*(?:"([^"]*)"|'([^']*)')"#;
+
+/// Compiled form of `RE_YOUTUBE`, built once on first use.
+pub static YOUTUBE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(RE_YOUTUBE).unwrap());
+
+/// Compiled form of `RE_XML_TRANSCRIPT`, built once on first use.
+pub static XML_TRANSCRIPT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(RE_XML_TRANSCRIPT).unwrap());
+
+/// Compiled form of `RE_MARKUP_TAG`, built once on first use.
+pub static MARKUP_TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(RE_MARKUP_TAG).unwrap());
+
+/// Compiled form of `RE_XML_ATTRIBUTE`, built once on first use.
+pub static XML_ATTRIBUTE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(RE_XML_ATTRIBUTE).unwrap());
@@ -1,12 +1,353 @@
+use crate::errors::YoutubeTranscriptError;
+use crate::format::{self, format_timestamp};
+use crate::utils::total_duration;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+use std::time::Duration;
+
 /// Configuration options for fetching transcripts.
-#[derive(Debug)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TranscriptConfig {
     /// The language code for the desired transcript (optional).
     pub lang: Option<String>,
+    /// Additional language codes to try, in order of preference, when `lang` is not available.
+    pub languages: Vec<String>,
+    /// The timeout applied to each HTTP request (optional).
+    pub timeout: Option<Duration>,
+    /// The maximum number of times a failed request is retried (defaults to no retries).
+    pub max_retries: u32,
+    /// The base delay between retries, doubled after each attempt (optional).
+    pub retry_delay: Option<Duration>,
+    /// Whether to prefer a manually authored caption track over an auto-generated one.
+    pub prefer_manual: bool,
+    /// Whether to only select auto-generated caption tracks, failing if none matches.
+    ///
+    /// Takes precedence over `prefer_manual`.
+    pub generated_only: bool,
+    /// Whether to fall back to a track sharing the primary language subtag (e.g. `en` for `en-GB`)
+    /// when no track matches a requested language exactly.
+    pub language_fallback: bool,
+    /// The maximum number of videos fetched at the same time by batch requests (optional).
+    pub concurrency: Option<usize>,
+    /// The language code to machine-translate the transcript into (optional).
+    ///
+    /// When set, the `lang` reported on each entry is this translation target.
+    pub translate_to: Option<String>,
+    /// The value of the `CONSENT` cookie used to bypass YouTube's cookie consent page (optional).
+    ///
+    /// Defaults to `YES+cb` when not set.
+    pub consent_cookie: Option<String>,
+    /// The URL of a proxy through which all requests are sent (optional).
+    ///
+    /// Only applies to HTTP clients built by the crate, not to caller-provided clients.
+    pub proxy: Option<String>,
+    /// The maximum number of idle connections kept open per host (optional).
+    ///
+    /// Defaults to no limit. Like `proxy`, it only applies to HTTP clients built by the crate.
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open for reuse (optional).
+    ///
+    /// Defaults to 90 seconds.
+    pub pool_idle_timeout: Option<Duration>,
+    /// The interval of TCP keep-alive probes on open connections (optional).
+    ///
+    /// Keep-alive is disabled when not set.
+    pub tcp_keepalive: Option<Duration>,
+    /// The User-Agent header sent with every request (optional).
+    ///
+    /// Defaults to a desktop Chrome User-Agent when not set.
+    pub user_agent: Option<String>,
+    /// The transcript format requested from YouTube via the `fmt` parameter (optional).
+    ///
+    /// Defaults to `srv1`. Only `srv1` and `json3` can be parsed into entries; other formats
+    /// such as `srv3` or `vtt` are intended for use with `fetch_transcript_raw`.
+    pub format: Option<String>,
+    /// The maximum size (in bytes) of a response body read from YouTube (optional).
+    ///
+    /// Responses exceeding it fail with `ResponseTooLarge` instead of being read into memory.
+    pub max_body_bytes: Option<usize>,
+    /// Extra HTTP headers sent with every request, as name-value pairs.
+    ///
+    /// They are applied after the crate's own headers, replacing any of the same name.
+    pub headers: Vec<(String, String)>,
+    /// The base URL of YouTube used to fetch video pages (optional).
+    ///
+    /// Defaults to `https://www.youtube.com`. Mainly useful for pointing the crate at a local
+    /// mock server in tests.
+    pub base_url: Option<String>,
+    /// Whether to emit one entry per word-timed segment instead of one entry per cue.
+    ///
+    /// Only applies to transcripts in the `json3` format, which carry word-level timing.
+    pub word_level: bool,
+    /// Whether a captcha page served instead of the video page is retried like a failed request.
+    ///
+    /// Retries follow `max_retries` and `retry_delay`. Once they are used up, fetching fails
+    /// with `TooManyRequests`.
+    pub retry_on_captcha: bool,
+    /// Whether parse anomalies fail the fetch instead of being worked around.
+    ///
+    /// In strict mode, a transcript without entries, an entry with a malformed timestamp, or a
+    /// track without a language code fails with `TranscriptParseFailed`. By default, such
+    /// entries are skipped and an empty transcript is returned as is.
+    pub strict: bool,
+    /// Whether line breaks within a cue are kept in the entry text.
+    ///
+    /// By default, all whitespace in a cue, including line breaks, is collapsed into single
+    /// spaces. When set, each line is cleaned up on its own and the lines are joined with `\n`.
+    pub preserve_newlines: bool,
+    /// How the caption tracks of a video are retrieved. Defaults to `Backend::Html`.
+    pub backend: Backend,
+    /// The language code of the page, sent as `Accept-Language` (optional).
+    ///
+    /// Defaults to the first requested transcript language. Setting it lets the interface
+    /// language differ from the transcript language, which is still selected by `lang` and
+    /// `languages`.
+    pub page_language: Option<String>,
+    /// Whether inline markup tags in cue text, such as `<i>` or `<font>`, are kept.
+    ///
+    /// By default, such tags are stripped and a `<br>` becomes a line break.
+    pub keep_markup: bool,
+    /// Whether to skip checking if a video page without captions is a captcha page.
+    ///
+    /// By default, such a page fails with `TooManyRequests`, or is fetched again when
+    /// `retry_on_captcha` is set. When set, the page is handled like any other page without
+    /// captions, in case the heuristic misclassifies it.
+    pub skip_captcha_detection: bool,
+}
+
+/// The source from which the caption tracks and details of a video are retrieved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Backend {
+    /// Scrape the player response embedded in the HTML of the watch page.
+    #[default]
+    Html,
+    /// Request the player response from YouTube's InnerTube API (`/youtubei/v1/player`) as the
+    /// web client. This does not depend on the layout of the watch page.
+    InnerTube,
+}
+
+impl TranscriptConfig {
+    /// Creates a builder for constructing a `TranscriptConfig`.
+    ///
+    /// # Returns
+    ///
+    /// * `TranscriptConfigBuilder` - A builder with all options unset.
+    pub fn builder() -> TranscriptConfigBuilder {
+        TranscriptConfigBuilder::default()
+    }
+
+    /// Returns the requested language codes in order of preference: `lang` followed by `languages`.
+    #[cfg_attr(not(feature = "fetch"), allow(dead_code))]
+    pub(crate) fn requested_languages(&self) -> Vec<&str> {
+        self.lang
+            .iter()
+            .chain(&self.languages)
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the language code of the page: `page_language` if set, otherwise the first
+    /// requested transcript language that is not blank.
+    #[cfg_attr(not(feature = "fetch"), allow(dead_code))]
+    pub(crate) fn interface_language(&self) -> Option<&str> {
+        self.page_language
+            .as_deref()
+            .into_iter()
+            .chain(self.requested_languages())
+            .find(|lang| !lang.trim().is_empty())
+    }
+}
+
+/// A builder for `TranscriptConfig`.
+#[derive(Debug, Default)]
+pub struct TranscriptConfigBuilder {
+    config: TranscriptConfig,
+}
+
+impl TranscriptConfigBuilder {
+    /// Sets the language code for the desired transcript.
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.config.lang = Some(lang.into());
+        self
+    }
+
+    /// Sets additional language codes to try, in order of preference, when `lang` is not available.
+    pub fn languages<I, S>(mut self, languages: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.languages = languages.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the timeout applied to each HTTP request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of times a failed request is retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.config.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay between retries.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.config.retry_delay = Some(retry_delay);
+        self
+    }
+
+    /// Sets whether to prefer a manually authored caption track over an auto-generated one.
+    pub fn prefer_manual(mut self, prefer_manual: bool) -> Self {
+        self.config.prefer_manual = prefer_manual;
+        self
+    }
+
+    /// Sets whether to only select auto-generated caption tracks.
+    pub fn generated_only(mut self, generated_only: bool) -> Self {
+        self.config.generated_only = generated_only;
+        self
+    }
+
+    /// Sets whether to fall back to a track sharing the primary language subtag when no track
+    /// matches a requested language exactly.
+    pub fn language_fallback(mut self, language_fallback: bool) -> Self {
+        self.config.language_fallback = language_fallback;
+        self
+    }
+
+    /// Sets the maximum number of videos fetched at the same time by batch requests.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.config.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Sets the language code to machine-translate the transcript into.
+    pub fn translate_to(mut self, lang: impl Into<String>) -> Self {
+        self.config.translate_to = Some(lang.into());
+        self
+    }
+
+    /// Sets the value of the `CONSENT` cookie used to bypass YouTube's cookie consent page.
+    pub fn consent_cookie(mut self, consent_cookie: impl Into<String>) -> Self {
+        self.config.consent_cookie = Some(consent_cookie.into());
+        self
+    }
+
+    /// Sets the URL of a proxy through which all requests are sent.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.config.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host.
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.config.pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Sets how long an idle connection is kept open for reuse.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the interval of TCP keep-alive probes on open connections.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.config.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Sets the User-Agent header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the transcript format requested from YouTube, e.g. `srv3` or `json3`.
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.config.format = Some(format.into());
+        self
+    }
+
+    /// Sets the maximum size (in bytes) of a response body read from YouTube.
+    pub fn max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.config.max_body_bytes = Some(max_body_bytes);
+        self
+    }
+
+    /// Adds an extra HTTP header sent with every request.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the base URL of YouTube used to fetch video pages, e.g. a local mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets whether to emit one entry per word-timed segment of a `json3` transcript.
+    pub fn word_level(mut self, word_level: bool) -> Self {
+        self.config.word_level = word_level;
+        self
+    }
+
+    /// Sets whether a captcha page served instead of the video page is retried.
+    pub fn retry_on_captcha(mut self, retry_on_captcha: bool) -> Self {
+        self.config.retry_on_captcha = retry_on_captcha;
+        self
+    }
+
+    /// Sets whether parse anomalies fail the fetch instead of being worked around.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.config.strict = strict;
+        self
+    }
+
+    /// Sets whether line breaks within a cue are kept in the entry text.
+    pub fn preserve_newlines(mut self, preserve_newlines: bool) -> Self {
+        self.config.preserve_newlines = preserve_newlines;
+        self
+    }
+
+    /// Sets how the caption tracks of a video are retrieved.
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.config.backend = backend;
+        self
+    }
+
+    /// Sets the language code of the page, independently of the transcript language.
+    pub fn page_language(mut self, page_language: impl Into<String>) -> Self {
+        self.config.page_language = Some(page_language.into());
+        self
+    }
+
+    /// Sets whether inline markup tags in cue text are kept.
+    pub fn keep_markup(mut self, keep_markup: bool) -> Self {
+        self.config.keep_markup = keep_markup;
+        self
+    }
+
+    /// Sets whether to skip checking if a video page without captions is a captcha page.
+    pub fn skip_captcha_detection(mut self, skip_captcha_detection: bool) -> Self {
+        self.config.skip_captcha_detection = skip_captcha_detection;
+        self
+    }
+
+    /// Builds the `TranscriptConfig`.
+    pub fn build(self) -> TranscriptConfig {
+        self.config
+    }
 }
 
 /// A struct representing a single entry in a YouTube transcript.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptResponse {
     /// The text of the transcript entry.
     pub text: String,
@@ -16,4 +357,443 @@ pub struct TranscriptResponse {
     pub offset: f64,
     /// The language code of the transcript entry.
     pub lang: String,
+    /// The human-readable language name of the caption track, e.g. `English (auto-generated)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_name: Option<String>,
+    /// Whether the transcript entry comes from an auto-generated caption track.
+    #[serde(default)]
+    pub is_generated: bool,
+    /// The position of the entry in its transcript, starting at 0.
+    ///
+    /// Entries produced by merging several cues keep the index of the first one.
+    #[serde(default)]
+    pub index: usize,
+}
+
+impl TranscriptResponse {
+    /// Returns the start of the entry formatted as `HH:MM:SS.mmm`.
+    pub fn start_timestamp(&self) -> String {
+        format_timestamp(self.offset, '.')
+    }
+
+    /// Returns the end of the entry formatted as `HH:MM:SS.mmm`.
+    pub fn end_timestamp(&self) -> String {
+        format_timestamp(self.offset + self.duration, '.')
+    }
+
+    /// Returns the start and end of the entry as `Duration`s from the start of the video.
+    ///
+    /// Negative or non-finite times are clamped to zero.
+    pub fn time_range(&self) -> (Duration, Duration) {
+        let to_duration =
+            |secs: f64| Duration::try_from_secs_f64(secs.max(0.0)).unwrap_or_default();
+        (
+            to_duration(self.offset),
+            to_duration(self.offset + self.duration),
+        )
+    }
+
+    /// Returns the start of the entry in whole milliseconds, rounded to the nearest one.
+    ///
+    /// Negative or non-finite times are clamped to zero.
+    pub fn offset_ms(&self) -> u64 {
+        seconds_to_millis(self.offset)
+    }
+
+    /// Returns the duration of the entry in whole milliseconds, rounded to the nearest one.
+    ///
+    /// Negative or non-finite durations are clamped to zero.
+    pub fn duration_ms(&self) -> u64 {
+        seconds_to_millis(self.duration)
+    }
+}
+
+/// Converts seconds to whole milliseconds, clamping negative and non-finite values to zero.
+fn seconds_to_millis(secs: f64) -> u64 {
+    if !secs.is_finite() {
+        return 0;
+    }
+    (secs.max(0.0) * 1000.0).round() as u64
+}
+
+impl fmt::Display for TranscriptResponse {
+    /// Formats the entry as `[HH:MM:SS.mmm] text`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.start_timestamp(), self.text)
+    }
+}
+
+/// A fetched transcript: the entries of a caption track, ordered by offset.
+///
+/// `Transcript` dereferences to a slice of `TranscriptResponse`, so slice methods such as
+/// `len` and `iter` are available directly.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Transcript(Vec<TranscriptResponse>);
+
+impl Transcript {
+    /// Consumes the transcript and returns its entries.
+    pub fn into_inner(self) -> Vec<TranscriptResponse> {
+        self.0
+    }
+
+    /// Returns the time (in seconds) from the start of the video to the end of the last entry.
+    pub fn full_duration(&self) -> f64 {
+        total_duration(self)
+    }
+
+    /// Renders the transcript as a SubRip (SRT) subtitle document.
+    pub fn to_srt(&self) -> String {
+        format::to_srt(self)
+    }
+
+    /// Renders the transcript as a WebVTT subtitle document.
+    pub fn to_vtt(&self) -> String {
+        format::to_vtt(self)
+    }
+
+    /// Joins the text of all entries into a single space-separated string.
+    pub fn to_plain_text(&self) -> String {
+        format::to_plain_text(self)
+    }
+
+    /// Joins the text of all entries with one entry per line.
+    pub fn to_text_lines(&self) -> String {
+        format::to_text_lines(self)
+    }
+
+    /// Joins the entries into paragraphs, starting a new one after pauses longer than `gap_secs`.
+    pub fn to_paragraphs(&self, gap_secs: f64) -> Vec<String> {
+        format::to_paragraphs(self, gap_secs)
+    }
+
+    /// Renders the transcript as a Markdown list, optionally linking each timestamp.
+    pub fn to_markdown(&self, link_prefix: Option<&str>) -> String {
+        format::to_markdown(self, link_prefix)
+    }
+
+    /// Renders the transcript as a CSV document.
+    pub fn to_csv(&self) -> String {
+        format::to_csv(self)
+    }
+
+    /// Renders the transcript as a JSON array, optionally indented.
+    pub fn to_json(&self, pretty: bool) -> String {
+        format::to_json(self, pretty)
+    }
+}
+
+impl From<Vec<TranscriptResponse>> for Transcript {
+    fn from(entries: Vec<TranscriptResponse>) -> Self {
+        Self(entries)
+    }
+}
+
+impl FromIterator<TranscriptResponse> for Transcript {
+    fn from_iter<I: IntoIterator<Item = TranscriptResponse>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Deref for Transcript {
+    type Target = [TranscriptResponse];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for Transcript {
+    type Item = TranscriptResponse;
+    type IntoIter = std::vec::IntoIter<TranscriptResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Transcript {
+    type Item = &'a TranscriptResponse;
+    type IntoIter = std::slice::Iter<'a, TranscriptResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// A struct describing a caption track available for a YouTube video.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptInfo {
+    /// The language code of the caption track.
+    pub language_code: String,
+    /// The human-readable language name of the caption track, if provided.
+    pub language_name: Option<String>,
+    /// Whether the caption track was automatically generated by YouTube.
+    pub is_generated: bool,
+    /// The URL from which the transcript content can be fetched.
+    pub base_url: String,
+}
+
+/// A struct holding a fetched transcript along with the caption track it was fetched from.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DetailedTranscript {
+    /// The selected caption track. Its `base_url` is the exact URL the transcript was fetched from.
+    pub track: TranscriptInfo,
+    /// The transcript entries.
+    pub entries: Vec<TranscriptResponse>,
+    /// Whether the entries are a machine translation of the caption track, requested through
+    /// `translate_to`, rather than the track's own text.
+    #[serde(default)]
+    pub is_translated: bool,
+    /// The language code of the caption track the entries come from. It differs from
+    /// `track.language_code` only when the entries are translated.
+    #[serde(default)]
+    pub source_lang: String,
+}
+
+/// A struct representing the details of a YouTube video.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoDetails {
+    /// The ID of the video.
+    pub video_id: String,
+    /// The title of the video.
+    pub title: String,
+    /// The name of the channel that published the video.
+    pub author: String,
+    /// The length of the video in seconds.
+    pub length_seconds: u64,
+}
+
+/// A struct representing a YouTube video ID parsed from a URL, along with the time it links to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoUrl {
+    /// The ID of the video.
+    pub id: String,
+    /// The start time (in seconds) given by the URL's `t` or `start` parameter, if any.
+    pub start: Option<f64>,
+}
+
+/// A validated YouTube video ID: exactly 11 ASCII letters, digits, `-`, or `_`.
+///
+/// Unlike `retrieve_video_id`, no URL forms are recognized, so the ID is known to be clean
+/// once constructed.
+/// Serializes as a plain string, and deserializing checks the ID like `VideoId::new`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct VideoId(String);
+
+impl VideoId {
+    /// Creates a `VideoId`, checking that `id` is a well-formed video ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The video ID, e.g. `dQw4w9WgXcQ`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VideoId)` - The video ID if it is well-formed.
+    /// * `Err(YoutubeTranscriptError)` - `InvalidVideoId` if it is not.
+    pub fn new(id: impl Into<String>) -> Result<Self, YoutubeTranscriptError> {
+        let id = id.into();
+        if !Self::is_well_formed(&id) {
+            return Err(YoutubeTranscriptError::InvalidVideoId);
+        }
+        Ok(Self(id))
+    }
+
+    /// Checks whether `id` consists of exactly 11 ASCII letters, digits, `-`, or `_`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - A string slice that may be a video ID.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether `id` is a well-formed video ID.
+    pub(crate) fn is_well_formed(id: &str) -> bool {
+        id.len() == 11
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Returns the video ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for VideoId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for VideoId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for VideoId {
+    type Error = YoutubeTranscriptError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        Self::new(id)
+    }
+}
+
+impl From<VideoId> for String {
+    fn from(id: VideoId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_builder() {
+        let config = TranscriptConfig::builder()
+            .lang("en")
+            .timeout(Duration::from_secs(10))
+            .build();
+        assert_eq!(config.lang.as_deref(), Some("en"));
+        assert_eq!(config.timeout, Some(Duration::from_secs(10)));
+
+        let config = TranscriptConfig::builder().build();
+        assert!(config.lang.is_none());
+    }
+
+    #[test]
+    fn test_transcript_response_timestamps() {
+        let entry = TranscriptResponse {
+            text: "hello".to_string(),
+            duration: 2.25,
+            offset: 83.45,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        };
+        assert_eq!(entry.start_timestamp(), "00:01:23.450");
+        assert_eq!(entry.end_timestamp(), "00:01:25.700");
+
+        let (start, end) = entry.time_range();
+        assert_eq!(start.as_millis(), 83450);
+        assert_eq!(end.as_millis(), 85700);
+
+        assert_eq!(entry.offset_ms(), 83450);
+        assert_eq!(entry.duration_ms(), 2250);
+        let entry = TranscriptResponse {
+            offset: -1.0,
+            duration: f64::NAN,
+            ..entry
+        };
+        assert_eq!(entry.offset_ms(), 0);
+        assert_eq!(entry.duration_ms(), 0);
+    }
+
+    #[test]
+    fn test_transcript_response_display() {
+        let entry = TranscriptResponse {
+            text: "text here".to_string(),
+            duration: 2.0,
+            offset: 83.45,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        };
+        assert_eq!(entry.to_string(), "[00:01:23.450] text here");
+    }
+
+    #[test]
+    fn test_transcript() {
+        let entry = |text: &str, offset: f64, duration: f64| TranscriptResponse {
+            text: text.to_string(),
+            duration,
+            offset,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        };
+        let transcript: Transcript = vec![entry("hello", 0.5, 1.5), entry("world", 2.0, 3.0)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(transcript.len(), 2);
+        assert_eq!(transcript.full_duration(), 5.0);
+        assert_eq!(transcript.to_plain_text(), "hello world");
+        assert!(transcript
+            .to_srt()
+            .starts_with("1\n00:00:00,500 --> 00:00:02,000\nhello"));
+
+        let texts: Vec<&str> = (&transcript).into_iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["hello", "world"]);
+        assert_eq!(transcript.into_iter().count(), 2);
+        assert_eq!(Transcript::default().full_duration(), 0.0);
+    }
+
+    #[test]
+    fn test_transcript_response_serialization() {
+        let entry = TranscriptResponse {
+            text: "hello".to_string(),
+            duration: 1.5,
+            offset: 0.5,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert_eq!(
+            json,
+            r#"{"text":"hello","duration":1.5,"offset":0.5,"lang":"en","is_generated":false,"index":0}"#
+        );
+
+        let decoded: TranscriptResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.text, "hello");
+        assert_eq!(decoded.offset, 0.5);
+
+        let json = r#"{"text":"hello","duration":1.5,"offset":0.5,"lang":"en"}"#;
+        let decoded: TranscriptResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(decoded.text, "hello");
+        assert!(!decoded.is_generated);
+        assert_eq!(decoded.index, 0);
+        assert_eq!(decoded.language_name, None);
+    }
+
+    #[test]
+    fn test_video_id() {
+        let id = VideoId::new("dQw4w9WgXcQ").unwrap();
+        assert_eq!(id.as_str(), "dQw4w9WgXcQ");
+        assert_eq!(id.to_string(), "dQw4w9WgXcQ");
+        assert!(VideoId::new("a-b_c123XYZ").is_ok());
+
+        for invalid in [
+            "",
+            "dQw4w9WgXc",
+            " dQw4w9WgXcQ",
+            "dQw4w9WgXc!",
+            "https://youtu.be/dQw4w9WgXcQ",
+        ] {
+            assert!(matches!(
+                VideoId::new(invalid),
+                Err(YoutubeTranscriptError::InvalidVideoId)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_video_id_serde() {
+        let id = VideoId::new("dQw4w9WgXcQ").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"dQw4w9WgXcQ\"");
+        assert_eq!(serde_json::from_str::<VideoId>(&json).unwrap(), id);
+
+        assert!(serde_json::from_str::<VideoId>("\"https://youtu.be/dQw4w9WgXcQ\"").is_err());
+    }
 }
@@ -0,0 +1,584 @@
+use crate::types::*;
+
+/// Merges consecutive transcript entries into windows of roughly `window_secs` seconds.
+///
+/// An entry joins the current window if it starts less than `window_secs` after the window's
+/// first entry; otherwise it starts a new window. The trimmed text of merged entries is joined
+/// with single spaces, skipping entries without text, and each window spans from the start of
+/// its first entry to the end of its last one.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries, ordered by offset.
+/// * `window_secs` - The target length of each window in seconds.
+///
+/// # Returns
+///
+/// * `Vec<TranscriptResponse>` - One entry per window.
+pub fn chunk_by_duration(
+    entries: &[TranscriptResponse],
+    window_secs: f64,
+) -> Vec<TranscriptResponse> {
+    let mut chunks: Vec<TranscriptResponse> = Vec::new();
+    for entry in entries {
+        let end = entry.offset + entry.duration;
+        match chunks.last_mut() {
+            Some(chunk) if entry.offset - chunk.offset < window_secs => {
+                let text = entry.text.trim();
+                if !text.is_empty() && !chunk.text.is_empty() {
+                    chunk.text.push(' ');
+                }
+                chunk.text.push_str(text);
+                chunk.duration = end.max(chunk.offset + chunk.duration) - chunk.offset;
+            }
+            _ => chunks.push(TranscriptResponse {
+                text: entry.text.trim().to_string(),
+                ..entry.clone()
+            }),
+        }
+    }
+    chunks
+}
+
+/// Merges adjacent entries that repeat the same phrase, as common in auto-generated captions.
+///
+/// Two adjacent entries are merged when their text is identical, or when the later text
+/// extends the earlier one by whole words (as rolling captions do). The merged entry keeps the longer text
+/// and spans from the start of the first entry to the end of the last one.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries, ordered by offset.
+///
+/// # Returns
+///
+/// * `Vec<TranscriptResponse>` - The entries with consecutive repeats merged.
+pub fn dedupe_consecutive(entries: &[TranscriptResponse]) -> Vec<TranscriptResponse> {
+    let mut deduped: Vec<TranscriptResponse> = Vec::new();
+    for entry in entries {
+        let text = entry.text.trim();
+        let end = entry.offset + entry.duration;
+        match deduped.last_mut() {
+            Some(previous) if repeats(&previous.text, text) => {
+                previous.duration = end.max(previous.offset + previous.duration) - previous.offset;
+                previous.text = text.to_string();
+            }
+            _ => deduped.push(TranscriptResponse {
+                text: text.to_string(),
+                ..entry.clone()
+            }),
+        }
+    }
+    deduped
+}
+
+/// Returns whether `text` repeats `previous`, either exactly or by appending whole words to it.
+fn repeats(previous: &str, text: &str) -> bool {
+    text == previous
+        || text
+            .strip_prefix(previous)
+            .is_some_and(|rest| !previous.is_empty() && rest.starts_with(char::is_whitespace))
+}
+
+/// Returns the entries displayed at any time within the window from `start_secs` to `end_secs`.
+///
+/// The window is half-open: an entry is included if it starts before `end_secs` and ends after
+/// `start_secs`, so entries straddling either boundary are included in full, while an entry
+/// ending exactly at `start_secs` is not. Entries without a duration are included if they start
+/// within the window.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+/// * `start_secs` - The start of the window in seconds.
+/// * `end_secs` - The end of the window in seconds.
+///
+/// # Returns
+///
+/// * `Vec<TranscriptResponse>` - Copies of the entries overlapping the window.
+pub fn slice_by_time(
+    entries: &[TranscriptResponse],
+    start_secs: f64,
+    end_secs: f64,
+) -> Vec<TranscriptResponse> {
+    entries
+        .iter()
+        .filter(|entry| {
+            let end = entry.offset + entry.duration.max(0.0);
+            entry.offset < end_secs && (end > start_secs || entry.offset >= start_secs)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Sorts transcript entries by offset, optionally clamping durations so entries do not overlap.
+///
+/// The sort is stable, so entries sharing an offset keep their relative order. When
+/// `clamp_overlaps` is set, an entry that runs past the start of the next one is shortened to end
+/// where the next one starts.
+///
+/// # Arguments
+///
+/// * `entries` - The transcript entries to normalize.
+/// * `clamp_overlaps` - Whether to shorten entries that overlap the next entry.
+///
+/// # Returns
+///
+/// * `Vec<TranscriptResponse>` - The entries in monotonic order.
+pub fn normalize(
+    mut entries: Vec<TranscriptResponse>,
+    clamp_overlaps: bool,
+) -> Vec<TranscriptResponse> {
+    entries.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    if clamp_overlaps {
+        for i in 1..entries.len() {
+            let next_offset = entries[i].offset;
+            let entry = &mut entries[i - 1];
+            if entry.offset + entry.duration > next_offset {
+                entry.duration = next_offset - entry.offset;
+            }
+        }
+    }
+    entries
+}
+
+/// Finds the entries whose text contains `query`, ignoring case.
+///
+/// Entry text is matched as is, since it was already decoded when the transcript was parsed.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+/// * `query` - The text to search for.
+///
+/// # Returns
+///
+/// * `Vec<(usize, &TranscriptResponse)>` - The index and entry of each match, in order.
+pub fn search<'a>(
+    entries: &'a [TranscriptResponse],
+    query: &str,
+) -> Vec<(usize, &'a TranscriptResponse)> {
+    let query = query.to_lowercase();
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.text.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// Finds the entries whose text contains `query`, along with up to `context` entries on each side.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+/// * `query` - The text to search for, matched as in `search`.
+/// * `context` - The number of neighboring entries to include before and after each match.
+///
+/// # Returns
+///
+/// * `Vec<(usize, &[TranscriptResponse])>` - The index of each match and the entries surrounding it.
+pub fn search_with_context<'a>(
+    entries: &'a [TranscriptResponse],
+    query: &str,
+    context: usize,
+) -> Vec<(usize, &'a [TranscriptResponse])> {
+    search(entries, query)
+        .into_iter()
+        .map(|(index, _)| {
+            let start = index.saturating_sub(context);
+            let end = index
+                .saturating_add(context)
+                .saturating_add(1)
+                .min(entries.len());
+            (index, &entries[start..end])
+        })
+        .collect()
+}
+
+/// A pair of aligned entries from two transcripts, as returned by `align_by_time`.
+pub type AlignedPair<'a> = (
+    Option<&'a TranscriptResponse>,
+    Option<&'a TranscriptResponse>,
+);
+
+/// Pairs the entries of two transcripts whose time ranges overlap, e.g. manual and auto-generated
+/// captions, or two languages of the same video.
+///
+/// Every overlapping pair is returned, so an entry spanning several entries of the other
+/// transcript appears in several pairs. Entries overlapping nothing are paired with `None`.
+/// Ranges that merely touch do not overlap, but two entries starting at the same time always do,
+/// even if they have no duration. Pairs are ordered by the start of their earlier entry.
+///
+/// For transcripts of consecutive cues, this takes roughly linear time after sorting. A long
+/// entry that overlaps many later ones keeps them all in the scan, so the worst case is
+/// proportional to the product of the lengths.
+///
+/// # Arguments
+///
+/// * `a` - A slice of `TranscriptResponse` entries.
+/// * `b` - A slice of `TranscriptResponse` entries to align with `a`.
+///
+/// # Returns
+///
+/// * `Vec<AlignedPair>` - The aligned pairs, with entries of `a` first and entries of `b` second.
+pub fn align_by_time<'a>(
+    a: &'a [TranscriptResponse],
+    b: &'a [TranscriptResponse],
+) -> Vec<AlignedPair<'a>> {
+    let by_offset = |entries: &'a [TranscriptResponse]| {
+        let mut sorted: Vec<_> = entries.iter().collect();
+        sorted.sort_by(|x, y| x.offset.total_cmp(&y.offset));
+        sorted
+    };
+    let (a, b) = (by_offset(a), by_offset(b));
+    let end = |entry: &TranscriptResponse| entry.offset + entry.duration.max(0.0);
+
+    // Step 1: Collect the overlapping pairs. Since both are sorted by start, the scan for each
+    // entry of `a` begins past the entries of `b` that ended before it, and stops at the first
+    // entry of `b` starting after it ends. Ends are not sorted, so the scan only begins past an
+    // entry once it and every entry before it have ended.
+    let mut aligned: Vec<(f64, AlignedPair<'a>)> = Vec::new();
+    let mut b_matched = vec![false; b.len()];
+    let (mut start, mut ended_by) = (0, f64::NEG_INFINITY);
+    for x in &a {
+        while let Some(y) = b.get(start) {
+            let ended = ended_by.max(end(y));
+            if ended > x.offset || y.offset == x.offset {
+                break;
+            }
+            ended_by = ended;
+            start += 1;
+        }
+
+        let mut matched = false;
+        for (j, y) in b.iter().enumerate().skip(start) {
+            if y.offset > end(x) || (y.offset == end(x) && y.offset != x.offset) {
+                break;
+            }
+            let overlaps = x.offset == y.offset || (x.offset < end(y) && y.offset < end(x));
+            if overlaps {
+                aligned.push((x.offset.min(y.offset), (Some(*x), Some(*y))));
+                b_matched[j] = true;
+                matched = true;
+            }
+        }
+        if !matched {
+            aligned.push((x.offset, (Some(*x), None)));
+        }
+    }
+
+    // Step 2: Add the entries of `b` that overlap nothing, then order all pairs by start
+    for (y, matched) in b.iter().zip(b_matched) {
+        if !matched {
+            aligned.push((y.offset, (None, Some(*y))));
+        }
+    }
+    aligned.sort_by(|x, y| x.0.total_cmp(&y.0));
+    aligned.into_iter().map(|(_, pair)| pair).collect()
+}
+
+/// Returns the time (in seconds) from the start of the video to the end of the last entry.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `f64` - The latest end time of any entry, or `0.0` if there are no entries.
+pub fn total_duration(entries: &[TranscriptResponse]) -> f64 {
+    entries
+        .iter()
+        .map(|entry| entry.offset + entry.duration)
+        .fold(0.0, f64::max)
+}
+
+/// Returns the total time (in seconds) during which captions are displayed.
+///
+/// Unlike `total_duration`, gaps between entries are not counted, so comparing the two
+/// shows how much of the video is covered by captions.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `f64` - The sum of the durations of all entries.
+pub fn spoken_duration(entries: &[TranscriptResponse]) -> f64 {
+    entries.iter().map(|entry| entry.duration.max(0.0)).sum()
+}
+
+/// Counts the whitespace-delimited words across all transcript entries.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `usize` - The total number of words.
+pub fn word_count(entries: &[TranscriptResponse]) -> usize {
+    entries
+        .iter()
+        .map(|entry| entry.text.split_whitespace().count())
+        .sum()
+}
+
+/// Counts the characters across all transcript entries.
+///
+/// Leading and trailing whitespace of each entry is not counted.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `TranscriptResponse` entries.
+///
+/// # Returns
+///
+/// * `usize` - The total number of characters.
+pub fn char_count(entries: &[TranscriptResponse]) -> usize {
+    entries
+        .iter()
+        .map(|entry| entry.text.trim().chars().count())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, offset: f64, duration: f64) -> TranscriptResponse {
+        TranscriptResponse {
+            text: text.to_string(),
+            duration,
+            offset,
+            lang: "en".to_string(),
+            language_name: None,
+            is_generated: false,
+            index: 0,
+        }
+    }
+
+    #[test]
+    fn test_chunk_by_duration() {
+        let entries = vec![
+            entry("one", 0.0, 2.0),
+            entry("two", 2.0, 3.0),
+            entry("three", 5.0, 2.0),
+            entry("four", 10.5, 1.0),
+        ];
+        let chunks = chunk_by_duration(&entries, 10.0);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "one two three");
+        assert_eq!(chunks[0].offset, 0.0);
+        assert_eq!(chunks[0].duration, 7.0);
+        assert_eq!(chunks[1].text, "four");
+        assert_eq!(chunks[1].offset, 10.5);
+        assert_eq!(chunks[1].duration, 1.0);
+    }
+
+    #[test]
+    fn test_chunk_by_duration_empty() {
+        assert!(chunk_by_duration(&[], 10.0).is_empty());
+
+        let entries = vec![
+            entry(" ", 0.0, 1.0),
+            entry("one", 1.0, 1.0),
+            entry("", 2.0, 1.0),
+            entry("two", 3.0, 1.0),
+            entry("\n", 4.0, 1.0),
+        ];
+        let chunks = chunk_by_duration(&entries, 10.0);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].text, "one two");
+        assert_eq!(chunks[0].duration, 5.0);
+    }
+
+    #[test]
+    fn test_dedupe_consecutive() {
+        let entries = vec![
+            entry("hello", 0.0, 2.0),
+            entry("hello", 1.0, 2.0),
+            entry("hello world", 2.5, 2.0),
+            entry("goodbye", 5.0, 1.0),
+            entry("goodbyes", 5.5, 1.0),
+            entry("hello", 6.0, 1.0),
+        ];
+        let deduped = dedupe_consecutive(&entries);
+
+        assert_eq!(deduped.len(), 4);
+        assert_eq!(deduped[0].text, "hello world");
+        assert_eq!(deduped[0].offset, 0.0);
+        assert_eq!(deduped[0].duration, 4.5);
+        assert_eq!(deduped[1].text, "goodbye");
+        assert_eq!(deduped[2].text, "goodbyes");
+        assert_eq!(deduped[3].text, "hello");
+    }
+
+    #[test]
+    fn test_normalize() {
+        let entries = vec![
+            entry("c", 5.0, 1.0),
+            entry("a", 0.0, 3.0),
+            entry("b", 2.0, 2.0),
+        ];
+        let sorted = normalize(entries, false);
+        let texts: Vec<_> = sorted.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["a", "b", "c"]);
+        assert_eq!(sorted[0].duration, 3.0);
+
+        let clamped = normalize(sorted, true);
+        let durations: Vec<_> = clamped.iter().map(|e| e.duration).collect();
+        assert_eq!(durations, [2.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_slice_by_time() {
+        let entries = vec![
+            entry("before", 100.0, 20.0),
+            entry("straddles start", 115.0, 10.0),
+            entry("inside", 130.0, 5.0),
+            entry("instant", 150.0, 0.0),
+            entry("straddles end", 175.0, 10.0),
+            entry("at end", 180.0, 2.0),
+        ];
+        let texts: Vec<String> = slice_by_time(&entries, 120.0, 180.0)
+            .into_iter()
+            .map(|entry| entry.text)
+            .collect();
+        assert_eq!(
+            texts,
+            ["straddles start", "inside", "instant", "straddles end"]
+        );
+
+        assert!(slice_by_time(&entries, 190.0, 200.0).is_empty());
+    }
+
+    #[test]
+    fn test_search() {
+        let entries = vec![
+            entry("Hello there", 0.0, 1.0),
+            entry("it's a test", 1.0, 1.0),
+            entry("nothing", 2.0, 1.0),
+            entry("say HELLO", 3.0, 1.0),
+            entry("type &lt; to escape <", 4.0, 1.0),
+        ];
+
+        let matches = search(&entries, "hello");
+        let indices: Vec<usize> = matches.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, [0, 3]);
+        assert_eq!(matches[1].1.text, "say HELLO");
+
+        assert_eq!(search(&entries, "IT'S")[0].0, 1);
+        assert!(search(&entries, "missing").is_empty());
+        assert_eq!(search(&entries, "&lt;")[0].0, 4);
+        assert!(search(&entries, "< to").is_empty());
+    }
+
+    #[test]
+    fn test_search_with_context() {
+        let entries = vec![
+            entry("one", 0.0, 1.0),
+            entry("two", 1.0, 1.0),
+            entry("three", 2.0, 1.0),
+            entry("four", 3.0, 1.0),
+        ];
+
+        let matches = search_with_context(&entries, "three", 1);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, 2);
+        let texts: Vec<&str> = matches[0].1.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["two", "three", "four"]);
+
+        let matches = search_with_context(&entries, "one", 2);
+        assert_eq!(matches[0].1.len(), 3);
+    }
+
+    #[test]
+    fn test_align_by_time() {
+        let a = vec![
+            entry("hello", 0.0, 2.0),
+            entry("world", 2.0, 2.0),
+            entry("alone", 10.0, 1.0),
+        ];
+        let b = vec![
+            entry("solo", 6.0, 1.0),
+            entry("hallo", 0.0, 1.0),
+            entry("welt", 1.5, 2.0),
+        ];
+
+        fn text(entry: Option<&TranscriptResponse>) -> Option<&str> {
+            entry.map(|e| e.text.as_str())
+        }
+        let pairs: Vec<_> = align_by_time(&a, &b)
+            .into_iter()
+            .map(|(x, y)| (text(x), text(y)))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (Some("hello"), Some("hallo")),
+                (Some("hello"), Some("welt")),
+                (Some("world"), Some("welt")),
+                (None, Some("solo")),
+                (Some("alone"), None),
+            ]
+        );
+
+        let a = vec![entry("now", 5.0, 0.0)];
+        let b = vec![entry("then", 4.0, 1.0), entry("now", 5.0, 0.0)];
+        let pairs: Vec<_> = align_by_time(&a, &b)
+            .into_iter()
+            .map(|(x, y)| (text(x), text(y)))
+            .collect();
+        assert_eq!(pairs, [(None, Some("then")), (Some("now"), Some("now"))]);
+
+        // A long entry of `b` still pairs with every later entry of `a` it spans.
+        let a = vec![
+            entry("one", 0.0, 1.0),
+            entry("two", 2.0, 1.0),
+            entry("three", 4.0, 1.0),
+        ];
+        let b = vec![
+            entry("long", 0.0, 10.0),
+            entry("short", 0.5, 0.5),
+            entry("late", 4.5, 1.0),
+        ];
+        let pairs: Vec<_> = align_by_time(&a, &b)
+            .into_iter()
+            .map(|(x, y)| (text(x), text(y)))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (Some("one"), Some("long")),
+                (Some("one"), Some("short")),
+                (Some("two"), Some("long")),
+                (Some("three"), Some("long")),
+                (Some("three"), Some("late")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_total_and_spoken_duration() {
+        let entries = vec![entry("one", 0.0, 2.0), entry("two", 10.0, 3.0)];
+        assert_eq!(total_duration(&entries), 13.0);
+        assert_eq!(spoken_duration(&entries), 5.0);
+        assert_eq!(total_duration(&[]), 0.0);
+        assert_eq!(spoken_duration(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_word_and_char_count() {
+        let entries = vec![
+            entry("it's a test", 0.0, 1.0),
+            entry(" rock\u{a0}& roll\n", 1.0, 1.0),
+            entry("&amp;", 2.0, 1.0),
+        ];
+        assert_eq!(word_count(&entries), 7);
+        assert_eq!(char_count(&entries), 27);
+        assert_eq!(word_count(&[]), 0);
+    }
+}